@@ -61,13 +61,20 @@ export default defineConfig({
             { text: 'Crossterm Adapter', link: '/guide/crossterm' },
             { text: 'owo-colors Adapter', link: '/guide/owo-colors' },
             { text: 'CSS Adapter', link: '/guide/css' },
+            { text: 'Tailwind Preset', link: '/guide/tailwind' },
+            { text: 'Design Tokens (DTCG)', link: '/guide/design-tokens' },
+            { text: 'Terminal Schemes', link: '/guide/terminal-schemes' },
+            { text: 'Theme Previews', link: '/guide/preview' },
             { text: 'Syntect Adapter', link: '/guide/syntect' },
+            { text: 'Tree-sitter Adapter', link: '/guide/tree-sitter' },
             { text: 'egui Adapter', link: '/guide/egui' },
             { text: 'Color Manipulation', link: '/guide/color-manipulation' },
             { text: 'App-Level Derivation', link: '/guide/derivation' },
             { text: 'ThemeBuilder', link: '/guide/builder' },
             { text: 'ThemeSelector Widget', link: '/guide/theme-selector' },
+            { text: 'GradientBlock Widget', link: '/guide/gradient-block' },
             { text: 'Custom Themes', link: '/guide/custom-themes' },
+            { text: 'Command-Line Tool', link: '/guide/command-line' },
           ],
         },
       ],
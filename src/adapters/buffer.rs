@@ -0,0 +1,497 @@
+//! Buffer adapter — render a ratatui [`Buffer`] as ANSI text, HTML, or SVG,
+//! and post-process its colors.
+//!
+//! Rendering is useful for snapshot tests and docs: draw a widget into a
+//! `Buffer`, then compare or publish it with its real colors and modifiers
+//! instead of asserting on individual cells.
+//!
+//! Filters ([`dim`], [`darken`], [`desaturate`], [`tint`], and the general
+//! [`map_colors`]) recolor a region after it's drawn. Run them on the frame
+//! buffer for "disabled", "inactive pane", and "modal backdrop" looks:
+//!
+//! ```rust,ignore
+//! terminal.draw(|frame| {
+//!     draw_app(frame);
+//!     buffer::dim(frame.buffer_mut(), frame.area(), &theme, 0.6);
+//!     frame.render_widget(modal, modal_area);
+//! })?;
+//! ```
+//!
+//! Cells with the same colors and modifiers are merged into runs. The
+//! trailing cells covered by a wide character are skipped, as ratatui does
+//! when drawing. HTML and SVG output take a [`Theme`] so cells with
+//! [`Color::Reset`] use `text.primary` and `bg.base`.
+//!
+//! ```rust,ignore
+//! use opaline::adapters::buffer::{buffer_to_ansi, buffer_to_svg};
+//!
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 60, 20));
+//! ThemeSelector::new().render(buf.area, &mut buf, &mut state);
+//! insta::assert_snapshot!(buffer_to_ansi(&buf));
+//! std::fs::write("docs/selector.svg", buffer_to_svg(&buf, &theme))?;
+//! ```
+
+use std::fmt::Write as _;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Modifier};
+use unicode_width::UnicodeWidthStr;
+
+use crate::adapters::{FONT_STACK, escape_markup};
+use crate::color::OpalineColor;
+use crate::names::tokens;
+use crate::theme::Theme;
+
+/// Cells sharing one style.
+struct Run {
+    x: u16,
+    width: u16,
+    text: String,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+/// Split each buffer row into runs of identically styled cells.
+fn rows(buffer: &Buffer) -> Vec<Vec<Run>> {
+    let area = buffer.area;
+    let mut rows = Vec::with_capacity(usize::from(area.height));
+
+    for y in area.top()..area.bottom() {
+        let mut runs: Vec<Run> = Vec::new();
+        let mut to_skip = 0usize;
+
+        for x in area.left()..area.right() {
+            if to_skip > 0 {
+                to_skip -= 1;
+                continue;
+            }
+            let Some(cell) = buffer.cell((x, y)) else {
+                continue;
+            };
+            let symbol = if cell.skip { " " } else { cell.symbol() };
+            let width = symbol.width().max(1);
+            to_skip = width - 1;
+            let width = u16::try_from(width).unwrap_or(1);
+
+            match runs.last_mut() {
+                Some(run)
+                    if run.fg == cell.fg && run.bg == cell.bg && run.modifier == cell.modifier =>
+                {
+                    run.text.push_str(symbol);
+                    run.width += width;
+                }
+                _ => runs.push(Run {
+                    x: x - area.left(),
+                    width,
+                    text: symbol.to_string(),
+                    fg: cell.fg,
+                    bg: cell.bg,
+                    modifier: cell.modifier,
+                }),
+            }
+        }
+
+        rows.push(runs);
+    }
+
+    rows
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// ANSI
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Render a buffer as text with ANSI SGR escape sequences, one line per row.
+///
+/// Each styled run starts with a full SGR (`\x1b[0;...m`) so snapshots don't
+/// depend on the previous run, and each line that changed style ends with a
+/// reset.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let mut lines = Vec::new();
+
+    for runs in rows(buffer) {
+        let mut line = String::new();
+        let mut styled = false;
+        for run in runs {
+            let sgr = sgr(run.fg, run.bg, run.modifier);
+            if sgr.is_empty() {
+                if styled {
+                    line.push_str("\x1b[0m");
+                    styled = false;
+                }
+            } else {
+                let _ = write!(line, "\x1b[0;{sgr}m");
+                styled = true;
+            }
+            line.push_str(&run.text);
+        }
+        if styled {
+            line.push_str("\x1b[0m");
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// SGR parameters for a style, without the reset prefix. Empty when unstyled.
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    const FLAGS: [(Modifier, u8); 9] = [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ];
+
+    let mut params: Vec<String> = FLAGS
+        .iter()
+        .filter(|(flag, _)| modifier.contains(*flag))
+        .map(|(_, code)| code.to_string())
+        .collect();
+    params.extend(sgr_color(fg, 30));
+    params.extend(sgr_color(bg, 40));
+    params.join(";")
+}
+
+/// SGR color parameter; `base` is 30 for foreground, 40 for background.
+fn sgr_color(color: Color, base: u8) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{r};{g};{b}", base + 8)),
+        Color::Indexed(i) => return Some(format!("{};5;{i}", base + 8)),
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+    };
+    Some(code.to_string())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// HTML
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Render a buffer as an HTML `<pre>` block with inline styles.
+pub fn buffer_to_html(buffer: &Buffer, theme: &Theme) -> String {
+    let defaults = Defaults::new(theme);
+    let mut html = format!(
+        "<pre style=\"margin: 0; padding: 8px; font-family: {FONT_STACK}; line-height: 1.2; \
+         color: {}; background: {}\">",
+        defaults.fg.to_hex(),
+        defaults.bg.to_hex()
+    );
+
+    let rows = rows(buffer);
+    let last = rows.len().saturating_sub(1);
+    for (idx, runs) in rows.into_iter().enumerate() {
+        for run in runs {
+            let (fg, bg) = defaults.resolve(&run);
+            let mut decls = Vec::new();
+            if fg != defaults.fg {
+                decls.push(format!("color: {}", fg.to_hex()));
+            }
+            if bg != defaults.bg {
+                decls.push(format!("background: {}", bg.to_hex()));
+            }
+            decls.extend(modifier_css(run.modifier).into_iter().map(str::to_string));
+
+            if decls.is_empty() {
+                html.push_str(&escape_markup(&run.text));
+            } else {
+                let _ = write!(
+                    html,
+                    "<span style=\"{}\">{}</span>",
+                    decls.join("; "),
+                    escape_markup(&run.text)
+                );
+            }
+        }
+        if idx < last {
+            html.push('\n');
+        }
+    }
+
+    html.push_str("</pre>\n");
+    html
+}
+
+fn modifier_css(modifier: Modifier) -> Vec<&'static str> {
+    let mut decls = Vec::new();
+    if modifier.contains(Modifier::BOLD) {
+        decls.push("font-weight: bold");
+    }
+    if modifier.contains(Modifier::DIM) {
+        decls.push("opacity: 0.7");
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        decls.push("font-style: italic");
+    }
+    if let Some(decoration) = text_decoration(modifier) {
+        decls.push(decoration);
+    }
+    if modifier.contains(Modifier::HIDDEN) {
+        decls.push("visibility: hidden");
+    }
+    decls
+}
+
+fn text_decoration(modifier: Modifier) -> Option<&'static str> {
+    match (
+        modifier.contains(Modifier::UNDERLINED),
+        modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => Some("text-decoration: underline line-through"),
+        (true, false) => Some("text-decoration: underline"),
+        (false, true) => Some("text-decoration: line-through"),
+        (false, false) => None,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// SVG
+// ═══════════════════════════════════════════════════════════════════════════════
+
+const CELL_WIDTH: u32 = 9;
+const CELL_HEIGHT: u32 = 18;
+const FONT_SIZE: u32 = 15;
+
+/// Render a buffer as an SVG image on a fixed cell grid.
+///
+/// Each run's text is stretched to its exact column width with
+/// `textLength`, so columns stay aligned whatever monospace font the viewer
+/// substitutes.
+pub fn buffer_to_svg(buffer: &Buffer, theme: &Theme) -> String {
+    let defaults = Defaults::new(theme);
+    let width = u32::from(buffer.area.width) * CELL_WIDTH;
+    let height = u32::from(buffer.area.height) * CELL_HEIGHT;
+
+    let mut backgrounds = String::new();
+    let mut text = String::new();
+
+    for (row, runs) in (0u32..).zip(rows(buffer)) {
+        let top = row * CELL_HEIGHT;
+        let baseline = top + CELL_HEIGHT - 5;
+        for run in runs {
+            let (fg, bg) = defaults.resolve(&run);
+            let x = u32::from(run.x) * CELL_WIDTH;
+            let run_width = u32::from(run.width) * CELL_WIDTH;
+
+            if bg != defaults.bg {
+                let _ = writeln!(
+                    backgrounds,
+                    "<rect x=\"{x}\" y=\"{top}\" width=\"{run_width}\" height=\"{CELL_HEIGHT}\" \
+                     fill=\"{}\"/>",
+                    bg.to_hex()
+                );
+            }
+            if run.text.trim().is_empty() || run.modifier.contains(Modifier::HIDDEN) {
+                continue;
+            }
+
+            let mut attrs = String::new();
+            if fg != defaults.fg {
+                let _ = write!(attrs, " fill=\"{}\"", fg.to_hex());
+            }
+            if run.modifier.contains(Modifier::BOLD) {
+                attrs.push_str(" font-weight=\"bold\"");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                attrs.push_str(" font-style=\"italic\"");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                attrs.push_str(" opacity=\"0.7\"");
+            }
+            if let Some(decoration) = text_decoration(run.modifier) {
+                let value = decoration.trim_start_matches("text-decoration: ");
+                let _ = write!(attrs, " text-decoration=\"{value}\"");
+            }
+            let _ = writeln!(
+                text,
+                "<text x=\"{x}\" y=\"{baseline}\" textLength=\"{run_width}\" \
+                 lengthAdjust=\"spacingAndGlyphs\"{attrs}>{}</text>",
+                escape_markup(&run.text)
+            );
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"{FONT_STACK}\" font-size=\"{FONT_SIZE}\" \
+         fill=\"{fg}\" xml:space=\"preserve\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n{backgrounds}{text}</svg>\n",
+        fg = defaults.fg.to_hex(),
+        bg = defaults.bg.to_hex(),
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Filters
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Transform the foreground and background of every cell in `area`.
+///
+/// Named and indexed colors go through the xterm palette, and
+/// [`Color::Reset`] becomes the theme's `text.primary` or `bg.base`, so
+/// every cell ends up with RGB colors. Symbols and modifiers are untouched.
+pub fn map_colors(
+    buffer: &mut Buffer,
+    area: Rect,
+    theme: &Theme,
+    mut f: impl FnMut(OpalineColor) -> OpalineColor,
+) {
+    let defaults = Defaults::new(theme);
+    let area = area.intersection(buffer.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let Some(cell) = buffer.cell_mut((x, y)) else {
+                continue;
+            };
+            let fg = to_rgb(cell.fg).unwrap_or(defaults.fg);
+            let bg = to_rgb(cell.bg).unwrap_or(defaults.bg);
+            cell.set_fg(f(fg).into());
+            cell.set_bg(f(bg).into());
+        }
+    }
+}
+
+/// Fade `area` toward the theme's `bg.base`, like a modal backdrop.
+/// `amount` 0.0 = unchanged, 1.0 = solid background.
+pub fn dim(buffer: &mut Buffer, area: Rect, theme: &Theme, amount: f32) {
+    let bg = Defaults::new(theme).bg;
+    map_colors(buffer, area, theme, |color| color.lerp(bg, amount));
+}
+
+/// Darken `area` toward black. See [`OpalineColor::darken`].
+pub fn darken(buffer: &mut Buffer, area: Rect, theme: &Theme, amount: f32) {
+    map_colors(buffer, area, theme, |color| color.darken(amount));
+}
+
+/// Drain color from `area`, for disabled or inactive panes. See
+/// [`OpalineColor::desaturate`].
+pub fn desaturate(buffer: &mut Buffer, area: Rect, theme: &Theme, amount: f32) {
+    map_colors(buffer, area, theme, |color| color.desaturate(amount));
+}
+
+/// Mix `area` toward `tint` by `amount`.
+pub fn tint(buffer: &mut Buffer, area: Rect, theme: &Theme, tint: OpalineColor, amount: f32) {
+    map_colors(buffer, area, theme, |color| color.lerp(tint, amount));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Shared helpers
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Colors used for [`Color::Reset`] cells.
+struct Defaults {
+    fg: OpalineColor,
+    bg: OpalineColor,
+}
+
+impl Defaults {
+    fn new(theme: &Theme) -> Self {
+        let (fg, bg) = if theme.is_light() {
+            (OpalineColor::BLACK, OpalineColor::WHITE)
+        } else {
+            (OpalineColor::WHITE, OpalineColor::BLACK)
+        };
+        Self {
+            fg: theme.try_color(tokens::TEXT_PRIMARY).unwrap_or(fg),
+            bg: theme.try_color(tokens::BG_BASE).unwrap_or(bg),
+        }
+    }
+
+    /// Concrete foreground and background for a run, applying `REVERSED`.
+    fn resolve(&self, run: &Run) -> (OpalineColor, OpalineColor) {
+        let fg = to_rgb(run.fg).unwrap_or(self.fg);
+        let bg = to_rgb(run.bg).unwrap_or(self.bg);
+        if run.modifier.contains(Modifier::REVERSED) {
+            (bg, fg)
+        } else {
+            (fg, bg)
+        }
+    }
+}
+
+/// Convert a ratatui color to RGB using the xterm palette for named and
+/// indexed colors. `Reset` has no RGB value.
+fn to_rgb(color: Color) -> Option<OpalineColor> {
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => OpalineColor::new(r, g, b),
+        Color::Indexed(i) => indexed(i),
+        Color::Black => indexed(0),
+        Color::Red => indexed(1),
+        Color::Green => indexed(2),
+        Color::Yellow => indexed(3),
+        Color::Blue => indexed(4),
+        Color::Magenta => indexed(5),
+        Color::Cyan => indexed(6),
+        Color::Gray => indexed(7),
+        Color::DarkGray => indexed(8),
+        Color::LightRed => indexed(9),
+        Color::LightGreen => indexed(10),
+        Color::LightYellow => indexed(11),
+        Color::LightBlue => indexed(12),
+        Color::LightMagenta => indexed(13),
+        Color::LightCyan => indexed(14),
+        Color::White => indexed(15),
+    };
+    Some(rgb)
+}
+
+/// The xterm 256-color palette.
+fn indexed(i: u8) -> OpalineColor {
+    const SYSTEM: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match i {
+        0..=15 => {
+            let (r, g, b) = SYSTEM[usize::from(i)];
+            OpalineColor::new(r, g, b)
+        }
+        16..=231 => {
+            let i = usize::from(i - 16);
+            OpalineColor::new(LEVELS[i / 36], LEVELS[(i / 6) % 6], LEVELS[i % 6])
+        }
+        232..=255 => {
+            let level = 8 + (i - 232) * 10;
+            OpalineColor::new(level, level, level)
+        }
+    }
+}
@@ -19,7 +19,7 @@ use crossterm::style::{Attribute, Color, ContentStyle, StyledContent};
 
 use crate::color::OpalineColor;
 #[cfg(feature = "gradients")]
-use crate::gradient::Gradient;
+use crate::gradient::{Gradient, GradientWrap};
 use crate::style::OpalineStyle;
 use crate::theme::Theme;
 #[cfg(feature = "gradients")]
@@ -118,40 +118,58 @@ impl Theme {
 #[cfg(feature = "gradients")]
 pub fn gradient_styled(text: &str, gradient: &Gradient) -> Vec<StyledContent<String>> {
     let graphemes: Vec<&str> = text.graphemes(true).collect();
-    if graphemes.is_empty() {
-        return vec![];
-    }
-
     let colors = gradient.generate(graphemes.len());
-    graphemes
-        .into_iter()
-        .zip(colors)
-        .map(|(grapheme, color)| {
-            let style = ContentStyle {
-                foreground_color: Some(color.into()),
-                ..ContentStyle::new()
-            };
-            style.apply(grapheme.to_string())
-        })
-        .collect()
+    colored(graphemes, colors)
+}
+
+/// Like [`gradient_styled`], with the gradient scrolled by `phase`.
+///
+/// The gradient loops seamlessly across the text (see
+/// [`GradientWrap::Repeat`]), and increasing `phase` moves the colors to
+/// the right; `1.0` is one full cycle.
+#[cfg(feature = "gradients")]
+pub fn gradient_styled_at_phase(
+    text: &str,
+    gradient: &Gradient,
+    phase: f32,
+) -> Vec<StyledContent<String>> {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let colors = gradient.generate_at_phase(graphemes.len(), phase, GradientWrap::Repeat);
+    colored(graphemes, colors)
 }
 
 /// Render a repeated character across `width` with gradient coloring.
 #[cfg(feature = "gradients")]
 pub fn gradient_bar(width: usize, ch: char, gradient: &Gradient) -> Vec<StyledContent<String>> {
-    if width == 0 {
-        return vec![];
-    }
+    let symbol = ch.to_string();
+    colored(vec![symbol.as_str(); width], gradient.generate(width))
+}
 
-    let colors = gradient.generate(width);
-    colors
+/// Like [`gradient_bar`], with the gradient scrolled by `phase`. See
+/// [`gradient_styled_at_phase`].
+#[cfg(feature = "gradients")]
+pub fn gradient_bar_at_phase(
+    width: usize,
+    ch: char,
+    gradient: &Gradient,
+    phase: f32,
+) -> Vec<StyledContent<String>> {
+    let symbol = ch.to_string();
+    let colors = gradient.generate_at_phase(width, phase, GradientWrap::Repeat);
+    colored(vec![symbol.as_str(); width], colors)
+}
+
+#[cfg(feature = "gradients")]
+fn colored(graphemes: Vec<&str>, colors: Vec<OpalineColor>) -> Vec<StyledContent<String>> {
+    graphemes
         .into_iter()
-        .map(|color| {
+        .zip(colors)
+        .map(|(grapheme, color)| {
             let style = ContentStyle {
                 foreground_color: Some(color.into()),
                 ..ContentStyle::new()
             };
-            style.apply(ch.to_string())
+            style.apply(grapheme.to_string())
         })
         .collect()
 }
@@ -2,6 +2,13 @@
 //!
 //! Produces CSS custom properties from theme tokens and CSS classes from
 //! theme styles. Gradient stops become `linear-gradient()` values.
+//! [`generate_stylesheet_pair`] combines a dark and a light theme into one
+//! stylesheet that follows `prefers-color-scheme` or a `data-theme` attribute.
+//!
+//! Every generator has a `_with` variant taking [`CssOptions`] for prefixes,
+//! selector scope, `var()` references, and minification. [`generate_scss_maps`]
+//! and [`generate_less_maps`] emit the same data for Sass and Less builds;
+//! they only take a name prefix.
 //!
 //! ```rust,ignore
 //! let theme = Theme::default();
@@ -12,8 +19,132 @@
 //! // }
 //! ```
 
+use crate::color::OpalineColor;
+use crate::resolver::ColorSource;
+use crate::style::OpalineStyle;
 use crate::theme::Theme;
 
+/// Output settings for the CSS generators.
+///
+/// The defaults reproduce the plain functions: `--opaline-` variables under
+/// `:root`, `.opaline-` classes with hex colors, pretty-printed.
+///
+/// ```rust
+/// use opaline::adapters::css::CssOptions;
+///
+/// let options = CssOptions::new()
+///     .var_prefix("app")
+///     .root_selector(".themed")
+///     .use_vars(true)
+///     .minify(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssOptions {
+    var_prefix: String,
+    root_selector: String,
+    class_prefix: String,
+    use_vars: bool,
+    minify: bool,
+}
+
+impl Default for CssOptions {
+    fn default() -> Self {
+        Self {
+            var_prefix: "opaline".to_string(),
+            root_selector: ":root".to_string(),
+            class_prefix: "opaline".to_string(),
+            use_vars: false,
+            minify: false,
+        }
+    }
+}
+
+impl CssOptions {
+    /// Default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefix for custom properties. An empty prefix drops it entirely
+    /// (`--accent-primary`).
+    #[must_use]
+    pub fn var_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.var_prefix = prefix.into();
+        self
+    }
+
+    /// Selector that holds the custom properties.
+    #[must_use]
+    pub fn root_selector(mut self, selector: impl Into<String>) -> Self {
+        self.root_selector = selector.into();
+        self
+    }
+
+    /// Prefix for style classes. An empty prefix drops it (`.keyword`).
+    #[must_use]
+    pub fn class_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.class_prefix = prefix.into();
+        self
+    }
+
+    /// Make class colors reference token variables (`var(--opaline-accent-primary)`)
+    /// instead of hex literals.
+    ///
+    /// Only colors whose style was defined against a token in the theme file
+    /// can be linked; the rest stay hex.
+    #[must_use]
+    pub fn use_vars(mut self, enabled: bool) -> Self {
+        self.use_vars = enabled;
+        self
+    }
+
+    /// Strip all optional whitespace from the output.
+    #[must_use]
+    pub fn minify(mut self, enabled: bool) -> Self {
+        self.minify = enabled;
+        self
+    }
+
+    pub(crate) fn var(&self, ident: &str) -> String {
+        prefixed("--", &self.var_prefix, ident)
+    }
+
+    fn class(&self, ident: &str) -> String {
+        prefixed(".", &self.class_prefix, ident)
+    }
+
+    fn separator(&self) -> &'static str {
+        if self.minify { "" } else { "\n\n" }
+    }
+
+    /// Format a rule block, indenting every line by `indent`.
+    fn block(&self, selector: &str, decls: &[String], indent: &str) -> String {
+        if self.minify {
+            return format!("{selector}{{{}}}", decls.join(""));
+        }
+        let mut lines = vec![format!("{indent}{selector} {{")];
+        lines.extend(decls.iter().map(|decl| format!("{indent}  {decl}")));
+        lines.push(format!("{indent}}}"));
+        lines.join("\n")
+    }
+
+    fn decl(&self, property: &str, value: &str) -> String {
+        if self.minify {
+            format!("{property}:{value};")
+        } else {
+            format!("{property}: {value};")
+        }
+    }
+}
+
+fn prefixed(sigil: &str, prefix: &str, ident: &str) -> String {
+    if prefix.is_empty() {
+        format!("{sigil}{ident}")
+    } else {
+        format!("{sigil}{prefix}-{ident}")
+    }
+}
+
 /// Generate CSS custom properties from all theme tokens.
 ///
 /// Token names are prefixed with `--opaline-` and dots/underscores become dashes:
@@ -22,16 +153,270 @@ use crate::theme::Theme;
 /// When the `gradients` feature is enabled, gradient stops are emitted as
 /// `linear-gradient(to right, ...)` values.
 pub fn generate_css_vars(theme: &Theme) -> String {
-    let mut lines = Vec::new();
-    lines.push(":root {".to_string());
+    generate_css_vars_with(theme, &CssOptions::default())
+}
+
+/// [`generate_css_vars`] with custom options.
+pub fn generate_css_vars_with(theme: &Theme, options: &CssOptions) -> String {
+    options.block(
+        &options.root_selector,
+        &var_declarations(theme, options),
+        "",
+    )
+}
+
+/// Generate CSS classes from all theme styles.
+///
+/// Style names are prefixed with `.opaline-` and underscores become dashes:
+/// `keyword` → `.opaline-keyword { color: #cba6f7; font-weight: bold; }`
+pub fn generate_css_classes(theme: &Theme) -> String {
+    generate_css_classes_with(theme, &CssOptions::default())
+}
+
+/// [`generate_css_classes`] with custom options.
+pub fn generate_css_classes_with(theme: &Theme, options: &CssOptions) -> String {
+    let mut blocks = Vec::new();
+
+    let mut names = theme.style_names();
+    names.sort_unstable();
+
+    for name in names {
+        let style = theme.style(name);
+        let sources = theme.style_sources(name).unwrap_or_default();
+        let color = |color: Option<OpalineColor>, source: Option<ColorSource>| {
+            if options.use_vars
+                && let Some(ColorSource::Token(token)) = source
+            {
+                return Some(format!("var({})", options.var(&css_ident(&token))));
+            }
+            color.map(OpalineColor::to_hex)
+        };
+        let props = style_properties(
+            &style,
+            color(style.fg, sources.fg),
+            color(style.bg, sources.bg),
+            options,
+        );
+
+        if !props.is_empty() {
+            blocks.push(options.block(&options.class(&css_ident(name)), &props, ""));
+        }
+    }
+
+    blocks.join(options.separator())
+}
+
+/// Generate a complete CSS stylesheet with custom properties and classes.
+pub fn generate_stylesheet(theme: &Theme) -> String {
+    generate_stylesheet_with(theme, &CssOptions::default())
+}
+
+/// [`generate_stylesheet`] with custom options.
+pub fn generate_stylesheet_with(theme: &Theme, options: &CssOptions) -> String {
+    let vars = generate_css_vars_with(theme, options);
+    let classes = generate_css_classes_with(theme, options);
+
+    if classes.is_empty() {
+        vars
+    } else {
+        format!("{vars}{}{classes}", options.separator())
+    }
+}
+
+/// Generate one stylesheet that switches between a dark and a light theme.
+///
+/// Each theme's variables are emitted twice: inside
+/// `@media (prefers-color-scheme: ...)` so the page follows the OS setting,
+/// and under `[data-theme="dark"]` / `[data-theme="light"]` so an explicit
+/// attribute on any element overrides it.
+///
+/// Classes are shared. Their colors point at per-style variables
+/// (`--opaline-style-keyword-fg`), so they follow whichever theme is active.
+/// Modifiers such as bold come from the dark theme, or from the light theme
+/// for styles only it defines.
+pub fn generate_stylesheet_pair(dark: &Theme, light: &Theme) -> String {
+    generate_stylesheet_pair_with(dark, light, &CssOptions::default())
+}
+
+/// [`generate_stylesheet_pair`] with custom options.
+///
+/// [`CssOptions::use_vars`] has no effect here; classes always reference the
+/// per-style variables.
+pub fn generate_stylesheet_pair_with(dark: &Theme, light: &Theme, options: &CssOptions) -> String {
+    let dark_vars = scheme_declarations(dark, options);
+    let light_vars = scheme_declarations(light, options);
+    let media = |scheme: &str, decls: &[String]| {
+        let query = format!("@media (prefers-color-scheme: {scheme})");
+        let root = options.block(&options.root_selector, decls, "  ");
+        if options.minify {
+            format!("{query}{{{root}}}")
+        } else {
+            format!("{query} {{\n{root}\n}}")
+        }
+    };
+
+    let mut blocks = vec![
+        media("dark", &dark_vars),
+        media("light", &light_vars),
+        options.block("[data-theme=\"dark\"]", &dark_vars, ""),
+        options.block("[data-theme=\"light\"]", &light_vars, ""),
+    ];
+
+    let mut names = dark.style_names();
+    names.extend(light.style_names());
+    names.sort_unstable();
+    names.dedup();
+
+    for name in names {
+        let dark_style = dark.try_style(name);
+        let light_style = light.try_style(name);
+        let Some(style) = dark_style.or(light_style) else {
+            continue;
+        };
+        let has = |pick: fn(&OpalineStyle) -> bool| {
+            dark_style.is_some_and(pick) || light_style.is_some_and(pick)
+        };
+
+        let ident = css_ident(name);
+        let style_var =
+            |channel: &str| format!("var({})", options.var(&format!("style-{ident}-{channel}")));
+        let fg = has(|s| s.fg.is_some()).then(|| style_var("fg"));
+        let bg = has(|s| s.bg.is_some()).then(|| style_var("bg"));
+        let props = style_properties(style, fg, bg, options);
+
+        if !props.is_empty() {
+            blocks.push(options.block(&options.class(&ident), &props, ""));
+        }
+    }
+
+    blocks.join(options.separator())
+}
+
+/// Generate Sass maps for tokens, gradients, and styles.
+///
+/// With `prefix` `"opaline"`, produces `$opaline-tokens`,
+/// `$opaline-gradients` (with the `gradients` feature), and `$opaline-styles`.
+/// An empty prefix drops it (`$tokens`). Style entries are nested maps of CSS
+/// properties:
+///
+/// ```scss
+/// $opaline-tokens: (
+///   "accent-primary": #e135ff,
+/// );
+/// $opaline-styles: (
+///   "keyword": ("color": #e135ff, "font-weight": bold),
+/// );
+/// ```
+pub fn generate_scss_maps(theme: &Theme, prefix: &str) -> String {
+    let maps = theme_maps(theme);
+    let mut blocks = Vec::new();
+
+    for (name, entries) in maps {
+        let mut lines = vec![format!("${}: (", prefixed("", prefix, name))];
+        for (key, value) in entries {
+            let value = match value {
+                MapValue::Plain(value) => value,
+                MapValue::Props(props) => {
+                    let pairs: Vec<String> =
+                        props.iter().map(|(k, v)| format!("\"{k}\": {v}")).collect();
+                    format!("({})", pairs.join(", "))
+                }
+            };
+            lines.push(format!("  \"{key}\": {value},"));
+        }
+        lines.push(");".to_string());
+        blocks.push(lines.join("\n"));
+    }
+
+    blocks.join("\n\n")
+}
+
+/// Generate Less maps (detached rulesets) for tokens, gradients, and styles.
+///
+/// Maps are named like [`generate_scss_maps`]. Values are looked up with
+/// Less 3.5+ map syntax, e.g. `@opaline-tokens[accent-primary]` or
+/// `@opaline-styles[keyword][color]`.
+pub fn generate_less_maps(theme: &Theme, prefix: &str) -> String {
+    let maps = theme_maps(theme);
+    let mut blocks = Vec::new();
+
+    for (name, entries) in maps {
+        let mut lines = vec![format!("@{}: {{", prefixed("", prefix, name))];
+        for (key, value) in entries {
+            match value {
+                MapValue::Plain(value) => lines.push(format!("  {key}: {value};")),
+                MapValue::Props(props) => {
+                    lines.push(format!("  {key} {{"));
+                    lines.extend(props.iter().map(|(k, v)| format!("    {k}: {v};")));
+                    lines.push("  }".to_string());
+                }
+            }
+        }
+        lines.push("}".to_string());
+        blocks.push(lines.join("\n"));
+    }
+
+    blocks.join("\n\n")
+}
+
+enum MapValue {
+    Plain(String),
+    Props(Vec<(&'static str, String)>),
+}
+
+/// Theme data as ordered `(map name, entries)` pairs for the preprocessor
+/// emitters.
+fn theme_maps(theme: &Theme) -> Vec<(&'static str, Vec<(String, MapValue)>)> {
+    let mut maps = Vec::new();
+
+    let mut names = theme.token_names();
+    names.sort_unstable();
+    let tokens = names
+        .into_iter()
+        .map(|name| (css_ident(name), MapValue::Plain(theme.color(name).to_hex())))
+        .collect();
+    maps.push(("tokens", tokens));
+
+    #[cfg(feature = "gradients")]
+    {
+        let mut names = theme.gradient_names();
+        names.sort_unstable();
+        let gradients = names
+            .into_iter()
+            .filter_map(|name| {
+                let gradient = theme.get_gradient(name)?;
+                Some((css_ident(name), MapValue::Plain(linear_gradient(gradient))))
+            })
+            .collect();
+        maps.push(("gradients", gradients));
+    }
+
+    let mut names = theme.style_names();
+    names.sort_unstable();
+    let styles = names
+        .into_iter()
+        .map(|name| {
+            (
+                css_ident(name),
+                MapValue::Props(style_pairs(&theme.style(name))),
+            )
+        })
+        .collect();
+    maps.push(("styles", styles));
+
+    maps
+}
+
+/// Token and gradient custom property declarations, sorted by name.
+fn var_declarations(theme: &Theme, options: &CssOptions) -> Vec<String> {
+    let mut decls = Vec::new();
 
     let mut names = theme.token_names();
     names.sort_unstable();
 
     for name in names {
         let color = theme.color(name);
-        let var_name = css_ident(name);
-        lines.push(format!("  --opaline-{var_name}: {};", color.to_hex()));
+        decls.push(options.decl(&options.var(&css_ident(name)), &color.to_hex()));
     }
 
     #[cfg(feature = "gradients")]
@@ -41,93 +426,114 @@ pub fn generate_css_vars(theme: &Theme) -> String {
 
         for name in grad_names {
             if let Some(gradient) = theme.get_gradient(name) {
-                let stops: Vec<String> = gradient.stops().iter().map(|c| c.to_hex()).collect();
-                let var_name = css_ident(name);
-                lines.push(format!(
-                    "  --opaline-gradient-{var_name}: linear-gradient(to right, {});",
-                    stops.join(", ")
-                ));
+                let var_name = options.var(&format!("gradient-{}", css_ident(name)));
+                decls.push(options.decl(&var_name, &linear_gradient(gradient)));
             }
         }
     }
 
-    lines.push("}".to_string());
-    lines.join("\n")
+    decls
 }
 
-/// Generate CSS classes from all theme styles.
-///
-/// Style names are prefixed with `.opaline-` and underscores become dashes:
-/// `keyword` → `.opaline-keyword { color: #cba6f7; font-weight: bold; }`
-pub fn generate_css_classes(theme: &Theme) -> String {
-    let mut blocks = Vec::new();
+#[cfg(feature = "gradients")]
+fn linear_gradient(gradient: &crate::gradient::Gradient) -> String {
+    let stops: Vec<String> = gradient.stops().iter().map(|c| c.to_hex()).collect();
+    format!("linear-gradient(to right, {})", stops.join(", "))
+}
+
+/// Token and gradient declarations plus per-style color variables.
+fn scheme_declarations(theme: &Theme, options: &CssOptions) -> Vec<String> {
+    let mut decls = var_declarations(theme, options);
 
     let mut names = theme.style_names();
     names.sort_unstable();
 
     for name in names {
         let style = theme.style(name);
-        let class_name = css_ident(name);
-        let mut props = Vec::new();
-
+        let ident = css_ident(name);
         if let Some(fg) = style.fg {
-            props.push(format!("  color: {};", fg.to_hex()));
+            let var_name = options.var(&format!("style-{ident}-fg"));
+            decls.push(options.decl(&var_name, &fg.to_hex()));
         }
         if let Some(bg) = style.bg {
-            props.push(format!("  background-color: {};", bg.to_hex()));
-        }
-        if style.bold {
-            props.push("  font-weight: bold;".to_string());
-        }
-        if style.dim {
-            props.push("  opacity: 0.7;".to_string());
-        }
-        if style.italic {
-            props.push("  font-style: italic;".to_string());
-        }
-
-        let mut decorations = Vec::new();
-        if style.underline {
-            decorations.push("underline");
-        }
-        if style.crossed_out {
-            decorations.push("line-through");
-        }
-        if !decorations.is_empty() {
-            props.push(format!("  text-decoration: {};", decorations.join(" ")));
+            let var_name = options.var(&format!("style-{ident}-bg"));
+            decls.push(options.decl(&var_name, &bg.to_hex()));
         }
+    }
 
-        if style.hidden {
-            props.push("  visibility: hidden;".to_string());
-        }
+    decls
+}
 
-        if !props.is_empty() {
-            blocks.push(format!(
-                ".opaline-{class_name} {{\n{}\n}}",
-                props.join("\n")
-            ));
-        }
+/// CSS declarations for a style, with colors supplied as ready-made values.
+fn style_properties(
+    style: &OpalineStyle,
+    fg: Option<String>,
+    bg: Option<String>,
+    options: &CssOptions,
+) -> Vec<String> {
+    let mut pairs = Vec::new();
+    if let Some(fg) = fg {
+        pairs.push(("color", fg));
+    }
+    if let Some(bg) = bg {
+        pairs.push(("background-color", bg));
     }
+    pairs.extend(modifier_pairs(style));
 
-    blocks.join("\n\n")
+    pairs
+        .iter()
+        .map(|(property, value)| options.decl(property, value))
+        .collect()
 }
 
-/// Generate a complete CSS stylesheet with custom properties and classes.
-pub fn generate_stylesheet(theme: &Theme) -> String {
-    let vars = generate_css_vars(theme);
-    let classes = generate_css_classes(theme);
+/// A style as property/value pairs with hex colors.
+fn style_pairs(style: &OpalineStyle) -> Vec<(&'static str, String)> {
+    let mut pairs = Vec::new();
+    if let Some(fg) = style.fg {
+        pairs.push(("color", fg.to_hex()));
+    }
+    if let Some(bg) = style.bg {
+        pairs.push(("background-color", bg.to_hex()));
+    }
+    pairs.extend(modifier_pairs(style));
+    pairs
+}
 
-    if classes.is_empty() {
-        vars
-    } else {
-        format!("{vars}\n\n{classes}")
+fn modifier_pairs(style: &OpalineStyle) -> Vec<(&'static str, String)> {
+    let mut pairs = Vec::new();
+
+    if style.bold {
+        pairs.push(("font-weight", "bold".to_string()));
+    }
+    if style.dim {
+        pairs.push(("opacity", "0.7".to_string()));
+    }
+    if style.italic {
+        pairs.push(("font-style", "italic".to_string()));
+    }
+
+    let mut decorations = Vec::new();
+    if style.underline {
+        decorations.push("underline");
     }
+    if style.crossed_out {
+        decorations.push("line-through");
+    }
+    if !decorations.is_empty() {
+        pairs.push(("text-decoration", decorations.join(" ")));
+    }
+
+    if style.hidden {
+        pairs.push(("visibility", "hidden".to_string()));
+    }
+
+    pairs
 }
 
 /// Normalize a token/style name into a valid CSS identifier fragment.
 /// Dots and underscores become dashes, and the remaining unsafe characters
 /// are escaped so arbitrary runtime names still produce valid selectors.
-fn css_ident(name: &str) -> String {
+pub(crate) fn css_ident(name: &str) -> String {
     let normalized = name.replace(['.', '_'], "-");
     escape_css_ident_fragment(&normalized)
 }
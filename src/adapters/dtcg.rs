@@ -0,0 +1,441 @@
+//! W3C Design Tokens (DTCG) adapter — JSON import and export.
+//!
+//! Exports follow the [Design Tokens Community Group] format used by Figma,
+//! Tokens Studio, and Style Dictionary:
+//!
+//! - `palette.*` — primitive `color` tokens with literal hex values
+//! - `tokens.*` — semantic `color` tokens, aliasing palette entries or other
+//!   tokens (`{palette.purple_500}`) so the reference graph survives
+//! - `gradients.*` — `gradient` tokens with evenly spaced stops
+//! - `$extensions["dev.opaline"]` — metadata, styles, and syntax overrides,
+//!   which DTCG has no types for
+//!
+//! Dotted Opaline names become nested groups: `accent.primary` is written as
+//! `tokens → accent → primary`.
+//!
+//! ```rust,ignore
+//! use opaline::adapters::dtcg;
+//!
+//! let json = dtcg::to_dtcg_string(&theme_file);
+//! let round_trip = dtcg::from_dtcg_str(&json)?;
+//! ```
+//!
+//! [Design Tokens Community Group]: https://www.designtokens.org/
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+use crate::color::OpalineColor;
+use crate::error::OpalineError;
+use crate::resolver;
+use crate::schema::{StyleDef, ThemeFile, ThemeMeta};
+use crate::theme::Theme;
+
+/// Vendor key for Opaline data stored under `$extensions`.
+pub const EXTENSION_KEY: &str = "dev.opaline";
+
+const PALETTE_GROUP: &str = "palette";
+const TOKENS_GROUP: &str = "tokens";
+const GRADIENTS_GROUP: &str = "gradients";
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Export
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Export a parsed [`ThemeFile`] as a DTCG JSON document.
+///
+/// Token and gradient references are written as aliases, so the document
+/// keeps the same palette → token structure as the TOML source.
+pub fn to_dtcg(theme_file: &ThemeFile) -> Value {
+    let palette_names: Vec<&str> = theme_file.palette.keys().map(String::as_str).collect();
+    let token_names: Vec<&str> = theme_file.tokens.keys().map(String::as_str).collect();
+    // Tokens resolve palette-first; gradient stops resolve tokens-first.
+    let token_alias = |reference: &str| alias_for(reference, &palette_names, &token_names, true);
+    let stop_alias = |reference: &str| alias_for(reference, &palette_names, &token_names, false);
+
+    let mut root = Map::new();
+
+    let mut palette = Map::new();
+    for (name, hex) in sorted(&theme_file.palette) {
+        insert_token(&mut palette, name, color_token(json!(hex.to_lowercase())));
+    }
+
+    let mut tokens = Map::new();
+    for (name, value) in sorted(&theme_file.tokens) {
+        insert_token(&mut tokens, name, color_token(token_alias(value)));
+    }
+
+    let mut gradients = Map::new();
+    for (name, stops) in sorted(&theme_file.gradients) {
+        let stops: Vec<Value> = stops.iter().map(|stop| stop_alias(stop)).collect();
+        insert_token(&mut gradients, name, gradient_token(stops));
+    }
+
+    insert_group(&mut root, PALETTE_GROUP, palette);
+    insert_group(&mut root, TOKENS_GROUP, tokens);
+    insert_group(&mut root, GRADIENTS_GROUP, gradients);
+
+    let styles: BTreeMap<&String, &StyleDef> = theme_file.styles.iter().collect();
+    let syntax: BTreeMap<&String, &String> = theme_file.syntax.iter().collect();
+    root.insert(
+        "$extensions".to_string(),
+        json!({
+            EXTENSION_KEY: {
+                "meta": theme_file.meta,
+                "styles": styles,
+                "syntax": syntax,
+            }
+        }),
+    );
+
+    Value::Object(root)
+}
+
+/// Export a parsed [`ThemeFile`] as pretty-printed DTCG JSON.
+pub fn to_dtcg_string(theme_file: &ThemeFile) -> String {
+    serde_json::to_string_pretty(&to_dtcg(theme_file)).expect("JSON values always serialize")
+}
+
+/// Export a resolved [`Theme`] as a DTCG JSON document.
+///
+/// References recorded during resolution are written back as aliases;
+/// colors set programmatically are written as literal hex values.
+pub fn theme_to_dtcg(theme: &Theme) -> Value {
+    to_dtcg(&theme.to_theme_file())
+}
+
+/// Export a resolved [`Theme`] as pretty-printed DTCG JSON.
+pub fn theme_to_dtcg_string(theme: &Theme) -> String {
+    serde_json::to_string_pretty(&theme_to_dtcg(theme)).expect("JSON values always serialize")
+}
+
+/// Convert a TOML color reference into a DTCG value: hex literals pass
+/// through, names become `{group.name}` aliases. The lookup order mirrors
+/// the resolver for the section the reference came from.
+fn alias_for(reference: &str, palette: &[&str], tokens: &[&str], palette_first: bool) -> Value {
+    if reference.starts_with('#') {
+        return json!(reference.to_lowercase());
+    }
+
+    let in_palette = palette.contains(&reference);
+    let in_tokens = tokens.contains(&reference);
+    if in_palette && (palette_first || !in_tokens) {
+        json!(format!("{{{PALETTE_GROUP}.{reference}}}"))
+    } else if in_tokens {
+        json!(format!("{{{TOKENS_GROUP}.{reference}}}"))
+    } else {
+        json!(reference)
+    }
+}
+
+fn color_token(value: Value) -> Value {
+    typed_token("color", value)
+}
+
+#[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+fn gradient_token(stops: Vec<Value>) -> Value {
+    let last = stops.len().saturating_sub(1).max(1) as f64;
+    let stops: Vec<Value> = stops
+        .into_iter()
+        .enumerate()
+        .map(|(i, color)| {
+            let mut stop = Map::new();
+            stop.insert("color".to_string(), color);
+            stop.insert("position".to_string(), json!(i as f64 / last));
+            Value::Object(stop)
+        })
+        .collect();
+    typed_token("gradient", Value::Array(stops))
+}
+
+fn typed_token(kind: &str, value: Value) -> Value {
+    let mut token = Map::new();
+    token.insert("$type".to_string(), json!(kind));
+    token.insert("$value".to_string(), value);
+    Value::Object(token)
+}
+
+fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&str, &V)> {
+    let mut entries: Vec<(&str, &V)> = map.iter().map(|(k, v)| (k.as_str(), v)).collect();
+    entries.sort_unstable_by_key(|&(k, _)| k);
+    entries
+}
+
+fn insert_group(root: &mut Map<String, Value>, name: &str, group: Map<String, Value>) {
+    if !group.is_empty() {
+        root.insert(name.to_string(), Value::Object(group));
+    }
+}
+
+/// Insert a token at its dotted path, creating nested groups as needed.
+///
+/// When a path segment is already a token (e.g. `text` and `text.primary`),
+/// the remainder is kept as a single dotted key so nothing is lost.
+fn insert_token(group: &mut Map<String, Value>, name: &str, token: Value) {
+    let Some((head, rest)) = name.split_once('.') else {
+        group.insert(name.to_string(), token);
+        return;
+    };
+
+    let child = group
+        .entry(head.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    match child {
+        Value::Object(child) if !child.contains_key("$value") => insert_token(child, rest, token),
+        _ => {
+            group.insert(name.to_string(), token);
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Import
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Import a DTCG JSON document into a [`ThemeFile`].
+///
+/// - Tokens under `palette` become palette entries, tokens under `tokens`
+///   become semantic tokens, and `gradient` tokens become gradients.
+/// - Anywhere else, literal colors become palette entries and aliases
+///   become tokens, both named by their full dotted path.
+/// - Aliases are rewritten to the imported names; unknown aliases fail.
+/// - Gradient stop positions are ignored — Opaline stops are evenly spaced.
+/// - Non-color token types are skipped.
+///
+/// Metadata, styles, and syntax overrides are read back from
+/// `$extensions["dev.opaline"]`; without it the theme is named `"Imported"`.
+pub fn from_dtcg(document: &Value) -> Result<ThemeFile, OpalineError> {
+    let Value::Object(root) = document else {
+        return Err(invalid("document root must be an object"));
+    };
+
+    let mut found = Vec::new();
+    collect_tokens(root, &mut Vec::new(), None, &mut found);
+
+    // Map every DTCG path to the Opaline name it imports as, so aliases can
+    // be rewritten before any values are converted.
+    let names: HashMap<String, String> = found
+        .iter()
+        .map(|token| (token.path.clone(), token.name().to_string()))
+        .collect();
+
+    let mut theme_file = ThemeFile {
+        meta: ThemeMeta::new("Imported"),
+        palette: HashMap::new(),
+        tokens: HashMap::new(),
+        styles: HashMap::new(),
+        gradients: HashMap::new(),
+        syntax: HashMap::new(),
+    };
+
+    for token in &found {
+        match token.kind.as_deref() {
+            Some("color") => {
+                let value = convert_color(&token.path, token.value, &names)?;
+                let is_literal = value.starts_with('#');
+                let name = token.name().to_string();
+                if token.group() == Some(PALETTE_GROUP)
+                    || (token.group() != Some(TOKENS_GROUP) && is_literal)
+                {
+                    if !is_literal {
+                        return Err(invalid(format!(
+                            "palette token '{}' must be a literal color",
+                            token.path
+                        )));
+                    }
+                    theme_file.palette.insert(name, value);
+                } else {
+                    theme_file.tokens.insert(name, value);
+                }
+            }
+            Some("gradient") => {
+                let Value::Array(stops) = token.value else {
+                    return Err(invalid(format!(
+                        "gradient '{}' must be an array of stops",
+                        token.path
+                    )));
+                };
+                let stops = stops
+                    .iter()
+                    .map(|stop| {
+                        convert_color(&token.path, stop.get("color").unwrap_or(stop), &names)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                theme_file.gradients.insert(token.name().to_string(), stops);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(ext) = root
+        .get("$extensions")
+        .and_then(|ext| ext.get(EXTENSION_KEY))
+    {
+        read_extension(ext, &mut theme_file)?;
+    }
+
+    Ok(theme_file)
+}
+
+/// Import DTCG JSON text into a [`ThemeFile`].
+pub fn from_dtcg_str(json: &str) -> Result<ThemeFile, OpalineError> {
+    let document: Value = serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?;
+    from_dtcg(&document)
+}
+
+/// Import DTCG JSON text and resolve it into a [`Theme`].
+pub fn load_from_dtcg_str(json: &str) -> Result<Theme, OpalineError> {
+    let theme_file = from_dtcg_str(json)?;
+    let resolved = resolver::resolve(&theme_file)?;
+    Ok(Theme::from_resolved(theme_file.meta, resolved))
+}
+
+/// A token found while walking the document.
+struct FoundToken<'a> {
+    /// Full dotted DTCG path (`tokens.accent.primary`).
+    path: String,
+    /// `$type`, inherited from the nearest group that declares one.
+    kind: Option<String>,
+    value: &'a Value,
+}
+
+impl FoundToken<'_> {
+    /// The top-level group this token lives under.
+    fn group(&self) -> Option<&str> {
+        self.path.split_once('.').map(|(group, _)| group)
+    }
+
+    /// The Opaline name: the path minus a known top-level group.
+    fn name(&self) -> &str {
+        match self.path.split_once('.') {
+            Some((PALETTE_GROUP | TOKENS_GROUP | GRADIENTS_GROUP, rest)) => rest,
+            _ => &self.path,
+        }
+    }
+}
+
+fn collect_tokens<'a>(
+    group: &'a Map<String, Value>,
+    path: &mut Vec<&'a str>,
+    inherited_type: Option<&'a str>,
+    found: &mut Vec<FoundToken<'a>>,
+) {
+    let group_type = group
+        .get("$type")
+        .and_then(Value::as_str)
+        .or(inherited_type);
+
+    for (key, child) in group {
+        if key.starts_with('$') {
+            continue;
+        }
+        let Value::Object(child) = child else {
+            continue;
+        };
+
+        path.push(key);
+        if let Some(value) = child.get("$value") {
+            let kind = child.get("$type").and_then(Value::as_str).or(group_type);
+            found.push(FoundToken {
+                path: path.join("."),
+                kind: kind.map(str::to_string),
+                value,
+            });
+        } else {
+            collect_tokens(child, path, group_type, found);
+        }
+        path.pop();
+    }
+}
+
+/// Convert a DTCG color value (literal or alias) into a TOML reference.
+fn convert_color(
+    path: &str,
+    value: &Value,
+    names: &HashMap<String, String>,
+) -> Result<String, OpalineError> {
+    if let Some(text) = value.as_str()
+        && let Some(target) = text.strip_prefix('{').and_then(|t| t.strip_suffix('}'))
+    {
+        return names
+            .get(target)
+            .cloned()
+            .ok_or_else(|| OpalineError::UnresolvedToken {
+                token: path.to_string(),
+                reference: target.to_string(),
+            });
+    }
+
+    parse_color(value)
+        .map(OpalineColor::to_hex)
+        .ok_or_else(|| invalid(format!("unsupported color value for '{path}': {value}")))
+}
+
+/// Parse a literal DTCG color: `#rgb`, `#rrggbb`, `#rrggbbaa` (alpha is
+/// dropped), or the object form with `hex` or sRGB `components`.
+#[allow(
+    clippy::cast_sign_loss,
+    clippy::cast_possible_truncation,
+    clippy::as_conversions
+)]
+fn parse_color(value: &Value) -> Option<OpalineColor> {
+    match value {
+        Value::String(hex) => {
+            let digits = hex.trim().strip_prefix('#')?;
+            if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let expanded: String = match digits.len() {
+                3 | 4 => digits.chars().take(3).flat_map(|c| [c, c]).collect(),
+                6 | 8 => digits[..6].to_string(),
+                _ => return None,
+            };
+            OpalineColor::from_hex(&format!("#{expanded}")).ok()
+        }
+        Value::Object(obj) => {
+            if let Some(color) = obj.get("hex").and_then(parse_color) {
+                return Some(color);
+            }
+            let space = obj.get("colorSpace").and_then(Value::as_str);
+            if !matches!(space, None | Some("srgb")) {
+                return None;
+            }
+            let components = obj.get("components")?.as_array()?;
+            let mut channels = components.iter().map(|c| {
+                c.as_f64()
+                    .map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+            });
+            Some(OpalineColor::new(
+                channels.next()??,
+                channels.next()??,
+                channels.next()??,
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn read_extension(ext: &Value, theme_file: &mut ThemeFile) -> Result<(), OpalineError> {
+    if let Some(meta) = ext.get("meta") {
+        theme_file.meta = ThemeMeta::deserialize(meta)
+            .map_err(|e| invalid(format!("invalid meta extension: {e}")))?;
+    }
+    if let Some(styles) = ext.get("styles") {
+        theme_file.styles = HashMap::deserialize(styles)
+            .map_err(|e| invalid(format!("invalid styles extension: {e}")))?;
+    }
+    if let Some(syntax) = ext.get("syntax") {
+        theme_file.syntax = HashMap::deserialize(syntax)
+            .map_err(|e| invalid(format!("invalid syntax extension: {e}")))?;
+    }
+    Ok(())
+}
+
+fn invalid(message: impl Into<String>) -> OpalineError {
+    OpalineError::InvalidFormat {
+        format: "DTCG".to_string(),
+        message: message.into(),
+    }
+}
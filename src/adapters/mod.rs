@@ -1,12 +1,27 @@
 #[cfg(feature = "ratatui")]
 pub mod ratatui;
 
+#[cfg(feature = "ratatui")]
+pub mod buffer;
+
 #[cfg(feature = "cli")]
 pub mod cli;
 
 #[cfg(feature = "css")]
 pub mod css;
 
+#[cfg(feature = "dtcg")]
+pub mod dtcg;
+
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
+
+#[cfg(feature = "preview")]
+pub mod preview;
+
+#[cfg(feature = "terminal-schemes")]
+pub mod terminal_schemes;
+
 #[cfg(feature = "crossterm")]
 pub mod crossterm;
 
@@ -16,8 +31,32 @@ pub mod owo_colors;
 #[cfg(feature = "syntect")]
 pub mod syntect;
 
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
 #[cfg(feature = "egui")]
 pub mod egui;
 
 #[cfg(feature = "iced")]
 pub mod iced;
+
+/// Monospace font stack for generated HTML and SVG.
+#[cfg(any(feature = "preview", feature = "ratatui"))]
+pub(crate) const FONT_STACK: &str = "ui-monospace, SFMono-Regular, Menlo, Consolas, monospace";
+
+/// Escape text for HTML and SVG content and attribute values.
+#[cfg(any(feature = "preview", feature = "ratatui"))]
+pub(crate) fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
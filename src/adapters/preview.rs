@@ -0,0 +1,600 @@
+//! Preview adapter — render a theme as a standalone HTML page or SVG card.
+//!
+//! Both outputs are self-contained (inline styles, no scripts, no external
+//! fonts) so CI can attach them to theme pull requests. They show:
+//!
+//! - palette swatches
+//! - every token with its hex value and WCAG contrast ratio against `bg.base`
+//! - every style rendered as sample text
+//! - gradient bars (with the `gradients` feature)
+//! - a short code sample colored with the `code.*` tokens
+//!
+//! ```rust,ignore
+//! let theme = opaline::load_by_name("silkcircuit-neon").expect("builtin");
+//! std::fs::write("preview.html", opaline::adapters::preview::render_html(&theme))?;
+//! std::fs::write("preview.svg", opaline::adapters::preview::render_svg(&theme))?;
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::adapters::{FONT_STACK, escape_markup};
+use crate::color::OpalineColor;
+use crate::names::tokens;
+use crate::style::OpalineStyle;
+use crate::theme::Theme;
+
+/// Code sample as lines of `(text, token)` segments.
+const CODE_SAMPLE: &[&[(&str, &str)]] = &[
+    &[(
+        "// Resolve a token through the palette",
+        tokens::CODE_COMMENT,
+    )],
+    &[
+        ("fn", tokens::CODE_KEYWORD),
+        (" ", tokens::TEXT_PRIMARY),
+        ("resolve", tokens::CODE_FUNCTION),
+        ("(name: ", tokens::TEXT_PRIMARY),
+        ("&str", tokens::CODE_TYPE),
+        (") -> ", tokens::TEXT_PRIMARY),
+        ("Option", tokens::CODE_TYPE),
+        ("<", tokens::TEXT_PRIMARY),
+        ("Color", tokens::CODE_TYPE),
+        ("> {", tokens::TEXT_PRIMARY),
+    ],
+    &[
+        ("    ", tokens::TEXT_PRIMARY),
+        ("let", tokens::CODE_KEYWORD),
+        (" depth = ", tokens::TEXT_PRIMARY),
+        ("42", tokens::CODE_NUMBER),
+        (";", tokens::TEXT_PRIMARY),
+    ],
+    &[
+        ("    ", tokens::TEXT_PRIMARY),
+        ("lookup", tokens::CODE_FUNCTION),
+        ("(name, ", tokens::TEXT_PRIMARY),
+        ("\"accent.primary\"", tokens::CODE_STRING),
+        (", depth)", tokens::TEXT_PRIMARY),
+    ],
+    &[("}", tokens::TEXT_PRIMARY)],
+];
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// HTML
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Render a self-contained HTML preview page.
+pub fn render_html(theme: &Theme) -> String {
+    let colors = PreviewColors::new(theme);
+    let mut html = String::new();
+
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title} — Opaline preview</title>\n<style>\n\
+         body {{ margin: 0; padding: 32px; font-family: {FONT_STACK}; background: {bg}; color: {fg}; }}\n\
+         h1 {{ margin: 0 0 4px; }}\n\
+         h2 {{ margin: 32px 0 12px; font-size: 16px; color: {muted}; }}\n\
+         .meta {{ margin: 0; color: {muted}; }}\n\
+         .swatches {{ display: flex; flex-wrap: wrap; gap: 12px; }}\n\
+         .swatch {{ width: 96px; font-size: 11px; }}\n\
+         .chip {{ height: 48px; border-radius: 6px; border: 1px solid {border}; margin-bottom: 4px; }}\n\
+         .swatch code {{ display: block; overflow-wrap: anywhere; }}\n\
+         table {{ border-collapse: collapse; font-size: 13px; }}\n\
+         th, td {{ text-align: left; padding: 4px 12px 4px 0; }}\n\
+         th {{ color: {muted}; font-weight: normal; }}\n\
+         .dot {{ display: inline-block; width: 14px; height: 14px; border-radius: 3px; vertical-align: middle; }}\n\
+         .styles {{ display: flex; flex-wrap: wrap; gap: 8px 24px; }}\n\
+         .gradient {{ height: 20px; border-radius: 4px; margin-bottom: 4px; }}\n\
+         .gradient-name {{ font-size: 12px; color: {muted}; margin-bottom: 12px; }}\n\
+         pre {{ background: {code_bg}; padding: 16px; border-radius: 6px; line-height: 1.5; }}\n\
+         .ln {{ color: {line_number}; user-select: none; }}\n\
+         </style>\n</head>\n<body>\n",
+        title = escape_markup(&theme.meta.name),
+        bg = colors.bg.to_hex(),
+        fg = colors.fg.to_hex(),
+        muted = colors.muted.to_hex(),
+        border = colors.border.to_hex(),
+        code_bg = colors.code_bg.to_hex(),
+        line_number = colors.line_number.to_hex(),
+    );
+
+    let _ = writeln!(html, "<h1>{}</h1>", escape_markup(&theme.meta.name));
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">{}</p>",
+        escape_markup(&meta_line(theme))
+    );
+
+    html_swatches(&mut html, theme, &colors);
+    html_styles(&mut html, theme);
+    #[cfg(feature = "gradients")]
+    html_gradients(&mut html, theme);
+    html_code(&mut html, theme, &colors);
+    html.push_str("</body>\n</html>\n");
+
+    html
+}
+
+/// Palette swatches and the token table.
+fn html_swatches(html: &mut String, theme: &Theme, colors: &PreviewColors) {
+    html.push_str("<h2>Palette</h2>\n<div class=\"swatches\">\n");
+    for (name, color) in palette(theme) {
+        let _ = writeln!(
+            html,
+            "<div class=\"swatch\"><div class=\"chip\" style=\"background: {hex}\"></div>\
+             <code>{name}</code><code>{hex}</code></div>",
+            hex = color.to_hex(),
+            name = escape_markup(name),
+        );
+    }
+    html.push_str("</div>\n");
+
+    let _ = writeln!(
+        html,
+        "<h2>Tokens</h2>\n<table>\n<tr><th></th><th>Token</th><th>Hex</th>\
+         <th>Contrast vs {}</th></tr>",
+        tokens::BG_BASE
+    );
+    for (name, color) in sorted_tokens(theme) {
+        let ratio = color.contrast_ratio(colors.bg);
+        let _ = writeln!(
+            html,
+            "<tr><td><span class=\"dot\" style=\"background: {hex}\"></span></td>\
+             <td><code>{name}</code></td><td><code>{hex}</code></td>\
+             <td>{ratio:.2}:1 {grade}</td></tr>",
+            hex = color.to_hex(),
+            name = escape_markup(name),
+            grade = wcag_grade(ratio),
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn html_styles(html: &mut String, theme: &Theme) {
+    html.push_str("<h2>Styles</h2>\n<div class=\"styles\">\n");
+    for name in sorted(theme.style_names()) {
+        let style = theme.style(name);
+        let _ = writeln!(
+            html,
+            "<span style=\"{}\">{}</span>",
+            css_declarations(&style),
+            escape_markup(name)
+        );
+    }
+    html.push_str("</div>\n");
+}
+
+#[cfg(feature = "gradients")]
+fn html_gradients(html: &mut String, theme: &Theme) {
+    let names = sorted(theme.gradient_names());
+    if !names.is_empty() {
+        html.push_str("<h2>Gradients</h2>\n");
+    }
+    for name in names {
+        let Some(gradient) = theme.get_gradient(name) else {
+            continue;
+        };
+        let stops: Vec<String> = gradient.stops().iter().map(|c| c.to_hex()).collect();
+        let _ = writeln!(
+            html,
+            "<div class=\"gradient\" style=\"background: linear-gradient(to right, {})\"></div>\
+             <div class=\"gradient-name\">{}</div>",
+            stops.join(", "),
+            escape_markup(name)
+        );
+    }
+}
+
+fn html_code(html: &mut String, theme: &Theme, colors: &PreviewColors) {
+    html.push_str("<h2>Code</h2>\n<pre>");
+    for (idx, line) in CODE_SAMPLE.iter().enumerate() {
+        let _ = write!(html, "<span class=\"ln\">{:>2}  </span>", idx + 1);
+        for &(text, token) in *line {
+            let _ = write!(
+                html,
+                "<span style=\"color: {}\">{}</span>",
+                colors.token(theme, token).to_hex(),
+                escape_markup(text)
+            );
+        }
+        html.push('\n');
+    }
+    html.push_str("</pre>\n");
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// SVG
+// ═══════════════════════════════════════════════════════════════════════════════
+
+const SVG_WIDTH: u32 = 760;
+const SVG_PAD: u32 = 24;
+const SWATCH: u32 = 32;
+const SWATCH_GAP: u32 = 8;
+const ROW: u32 = 20;
+const SECTION_GAP: u32 = 28;
+
+/// Render a self-contained SVG preview card.
+pub fn render_svg(theme: &Theme) -> String {
+    let mut card = SvgCard {
+        theme,
+        colors: PreviewColors::new(theme),
+        body: String::new(),
+        defs: String::new(),
+        y: SVG_PAD + 20,
+    };
+
+    card.header();
+    card.palette();
+    card.tokens();
+    card.styles();
+    #[cfg(feature = "gradients")]
+    card.gradients();
+    card.code();
+    card.finish()
+}
+
+const CONTENT_WIDTH: u32 = SVG_WIDTH - 2 * SVG_PAD;
+
+/// SVG output under construction. `y` is the baseline of the next row.
+struct SvgCard<'a> {
+    theme: &'a Theme,
+    colors: PreviewColors,
+    body: String,
+    defs: String,
+    y: u32,
+}
+
+impl SvgCard<'_> {
+    fn header(&mut self) {
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{SVG_PAD}\" y=\"{}\" font-size=\"20\" font-weight=\"bold\">{}</text>",
+            self.y,
+            escape_markup(&self.theme.meta.name)
+        );
+        self.y += ROW;
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{SVG_PAD}\" y=\"{}\" fill=\"{}\">{}</text>",
+            self.y,
+            self.colors.muted.to_hex(),
+            escape_markup(&meta_line(self.theme))
+        );
+    }
+
+    fn section_title(&mut self, title: &str) {
+        self.y += SECTION_GAP;
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{SVG_PAD}\" y=\"{}\" font-size=\"15\" fill=\"{}\">{title}</text>",
+            self.y,
+            self.colors.muted.to_hex()
+        );
+        self.y += ROW + 8;
+    }
+
+    /// Palette swatches, wrapped to the card width.
+    fn palette(&mut self) {
+        self.section_title("Palette");
+        let per_row = (CONTENT_WIDTH + SWATCH_GAP) / (SWATCH + SWATCH_GAP);
+        let mut col = 0;
+        for (name, color) in palette(self.theme) {
+            if col == per_row {
+                col = 0;
+                self.y += SWATCH + SWATCH_GAP;
+            }
+            let x = SVG_PAD + col * (SWATCH + SWATCH_GAP);
+            let _ = writeln!(
+                self.body,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{SWATCH}\" height=\"{SWATCH}\" rx=\"4\" \
+                 fill=\"{hex}\" stroke=\"{border}\"><title>{name} {hex}</title></rect>",
+                y = self.y,
+                hex = color.to_hex(),
+                border = self.colors.border.to_hex(),
+                name = escape_markup(name),
+            );
+            col += 1;
+        }
+        if col > 0 {
+            self.y += SWATCH;
+        }
+    }
+
+    /// Tokens in two columns: dot, name, hex, contrast.
+    fn tokens(&mut self) {
+        self.section_title("Tokens");
+        let column_width = CONTENT_WIDTH / 2;
+        let token_list = sorted_tokens(self.theme);
+        let rows = token_list.len().div_ceil(2);
+        for (idx, (name, color)) in token_list.iter().enumerate() {
+            let (row, column) = if idx < rows {
+                (idx, 0)
+            } else {
+                (idx - rows, 1)
+            };
+            let x = SVG_PAD + column * column_width;
+            let row_y = self.y + to_u32(row) * ROW;
+            let ratio = color.contrast_ratio(self.colors.bg);
+            let _ = writeln!(
+                self.body,
+                "<rect x=\"{x}\" y=\"{top}\" width=\"12\" height=\"12\" rx=\"2\" fill=\"{hex}\"/>\
+                 <text x=\"{name_x}\" y=\"{row_y}\">{name}</text>\
+                 <text x=\"{hex_x}\" y=\"{row_y}\" fill=\"{muted}\">{hex} {ratio:.2}:1 {grade}</text>",
+                top = row_y - 11,
+                hex = color.to_hex(),
+                name_x = x + 20,
+                name = escape_markup(name),
+                hex_x = x + 180,
+                muted = self.colors.muted.to_hex(),
+                grade = wcag_grade(ratio),
+            );
+        }
+        self.y += to_u32(rows) * ROW;
+    }
+
+    /// Styles, three per row.
+    fn styles(&mut self) {
+        self.section_title("Styles");
+        let style_names = sorted(self.theme.style_names());
+        let style_width = CONTENT_WIDTH / 3;
+        for (idx, name) in style_names.iter().enumerate() {
+            let idx = to_u32(idx);
+            let x = SVG_PAD + (idx % 3) * style_width;
+            let row_y = self.y + (idx / 3) * ROW;
+            let style = self.theme.style(name);
+            if let Some(bg) = style.bg {
+                let _ = writeln!(
+                    self.body,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{ROW}\" fill=\"{}\"/>",
+                    x - 4,
+                    row_y - 15,
+                    style_width - 8,
+                    bg.to_hex()
+                );
+            }
+            let _ = writeln!(
+                self.body,
+                "<text x=\"{x}\" y=\"{row_y}\"{}>{}</text>",
+                svg_text_attributes(&style),
+                escape_markup(name)
+            );
+        }
+        self.y += to_u32(style_names.len()).div_ceil(3) * ROW;
+    }
+
+    #[cfg(feature = "gradients")]
+    fn gradients(&mut self) {
+        let names = sorted(self.theme.gradient_names());
+        if !names.is_empty() {
+            self.section_title("Gradients");
+        }
+        for (idx, name) in names.into_iter().enumerate() {
+            let Some(gradient) = self.theme.get_gradient(name) else {
+                continue;
+            };
+            let _ = write!(self.defs, "<linearGradient id=\"gradient-{idx}\">");
+            let last = gradient.len().saturating_sub(1).max(1);
+            for (stop, color) in gradient.stops().iter().enumerate() {
+                let _ = write!(
+                    self.defs,
+                    "<stop offset=\"{}%\" stop-color=\"{}\"/>",
+                    stop * 100 / last,
+                    color.to_hex()
+                );
+            }
+            self.defs.push_str("</linearGradient>\n");
+
+            let _ = writeln!(
+                self.body,
+                "<rect x=\"{SVG_PAD}\" y=\"{}\" width=\"{}\" height=\"14\" rx=\"3\" \
+                 fill=\"url(#gradient-{idx})\"/>\
+                 <text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                self.y - 11,
+                CONTENT_WIDTH - 140,
+                SVG_WIDTH - SVG_PAD - 130,
+                self.y,
+                self.colors.muted.to_hex(),
+                escape_markup(name)
+            );
+            self.y += ROW;
+        }
+    }
+
+    /// Code sample on the code background.
+    fn code(&mut self) {
+        self.section_title("Code");
+        let code_height = to_u32(CODE_SAMPLE.len()) * ROW + 16;
+        let _ = writeln!(
+            self.body,
+            "<rect x=\"{SVG_PAD}\" y=\"{}\" width=\"{CONTENT_WIDTH}\" height=\"{code_height}\" \
+             rx=\"6\" fill=\"{}\"/>",
+            self.y - 14,
+            self.colors.code_bg.to_hex()
+        );
+        self.y += 6;
+        for (idx, line) in CODE_SAMPLE.iter().enumerate() {
+            let _ = write!(
+                self.body,
+                "<text x=\"{}\" y=\"{}\" xml:space=\"preserve\"><tspan fill=\"{}\">{:>2}  </tspan>",
+                SVG_PAD + 12,
+                self.y,
+                self.colors.line_number.to_hex(),
+                idx + 1
+            );
+            for &(text, token) in *line {
+                let _ = write!(
+                    self.body,
+                    "<tspan fill=\"{}\">{}</tspan>",
+                    self.colors.token(self.theme, token).to_hex(),
+                    escape_markup(text)
+                );
+            }
+            self.body.push_str("</text>\n");
+            self.y += ROW;
+        }
+    }
+
+    fn finish(self) -> String {
+        let height = self.y + SVG_PAD;
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{SVG_WIDTH}\" height=\"{height}\" \
+             viewBox=\"0 0 {SVG_WIDTH} {height}\" font-family=\"{FONT_STACK}\" font-size=\"13\" \
+             fill=\"{}\">",
+            self.colors.fg.to_hex()
+        );
+        if !self.defs.is_empty() {
+            let _ = write!(svg, "<defs>\n{}</defs>\n", self.defs);
+        }
+        let _ = writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" rx=\"12\" fill=\"{}\"/>",
+            self.colors.bg.to_hex()
+        );
+        svg.push_str(&self.body);
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn svg_text_attributes(style: &OpalineStyle) -> String {
+    let mut attrs = String::new();
+    if let Some(fg) = style.fg {
+        let _ = write!(attrs, " fill=\"{}\"", fg.to_hex());
+    }
+    if style.bold {
+        attrs.push_str(" font-weight=\"bold\"");
+    }
+    if style.italic {
+        attrs.push_str(" font-style=\"italic\"");
+    }
+    if style.dim {
+        attrs.push_str(" opacity=\"0.7\"");
+    }
+    if let Some(decoration) = text_decoration(style) {
+        let _ = write!(attrs, " text-decoration=\"{decoration}\"");
+    }
+    attrs
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Shared helpers
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Chrome colors for the preview, falling back to black/white by variant.
+struct PreviewColors {
+    bg: OpalineColor,
+    fg: OpalineColor,
+    muted: OpalineColor,
+    border: OpalineColor,
+    code_bg: OpalineColor,
+    line_number: OpalineColor,
+}
+
+impl PreviewColors {
+    fn new(theme: &Theme) -> Self {
+        let (bg_default, fg_default) = if theme.is_light() {
+            (OpalineColor::WHITE, OpalineColor::BLACK)
+        } else {
+            (OpalineColor::BLACK, OpalineColor::WHITE)
+        };
+        let bg = theme.try_color(tokens::BG_BASE).unwrap_or(bg_default);
+        let fg = theme.try_color(tokens::TEXT_PRIMARY).unwrap_or(fg_default);
+        let muted = theme
+            .try_color(tokens::TEXT_MUTED)
+            .unwrap_or_else(|| fg.lerp(bg, 0.4));
+        Self {
+            bg,
+            fg,
+            muted,
+            border: theme
+                .try_color(tokens::BORDER_UNFOCUSED)
+                .unwrap_or_else(|| fg.lerp(bg, 0.7)),
+            code_bg: theme.try_color(tokens::BG_CODE).unwrap_or(bg),
+            line_number: theme.try_color(tokens::CODE_LINE_NUMBER).unwrap_or(muted),
+        }
+    }
+
+    fn token(&self, theme: &Theme, token: &str) -> OpalineColor {
+        theme.try_color(token).unwrap_or(self.fg)
+    }
+}
+
+fn meta_line(theme: &Theme) -> String {
+    let mut parts = vec![if theme.is_light() { "light" } else { "dark" }.to_string()];
+    if let Some(author) = &theme.meta.author {
+        parts.push(format!("by {author}"));
+    }
+    if let Some(description) = &theme.meta.description {
+        parts.push(description.clone());
+    }
+    parts.join(" · ")
+}
+
+fn palette(theme: &Theme) -> Vec<(&str, OpalineColor)> {
+    sorted(theme.palette_names())
+        .into_iter()
+        .filter_map(|name| Some((name, theme.palette_color(name)?)))
+        .collect()
+}
+
+fn sorted_tokens(theme: &Theme) -> Vec<(&str, OpalineColor)> {
+    sorted(theme.token_names())
+        .into_iter()
+        .map(|name| (name, theme.color(name)))
+        .collect()
+}
+
+fn sorted(mut names: Vec<&str>) -> Vec<&str> {
+    names.sort_unstable();
+    names
+}
+
+fn wcag_grade(ratio: f32) -> &'static str {
+    if ratio >= 7.0 {
+        "AAA"
+    } else if ratio >= 4.5 {
+        "AA"
+    } else if ratio >= 3.0 {
+        "AA large"
+    } else {
+        ""
+    }
+}
+
+fn css_declarations(style: &OpalineStyle) -> String {
+    let mut decls = Vec::new();
+    if let Some(fg) = style.fg {
+        decls.push(format!("color: {}", fg.to_hex()));
+    }
+    if let Some(bg) = style.bg {
+        decls.push(format!("background: {}", bg.to_hex()));
+    }
+    if style.bold {
+        decls.push("font-weight: bold".to_string());
+    }
+    if style.italic {
+        decls.push("font-style: italic".to_string());
+    }
+    if style.dim {
+        decls.push("opacity: 0.7".to_string());
+    }
+    if let Some(decoration) = text_decoration(style) {
+        decls.push(format!("text-decoration: {decoration}"));
+    }
+    decls.join("; ")
+}
+
+fn text_decoration(style: &OpalineStyle) -> Option<&'static str> {
+    match (style.underline, style.crossed_out) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    }
+}
+
+fn to_u32(n: usize) -> u32 {
+    u32::try_from(n).unwrap_or(u32::MAX)
+}
@@ -14,10 +14,12 @@ use ratatui_core::text::Span;
 use ratatui_core::text::{Line, Text};
 #[cfg(feature = "gradients")]
 use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "gradients")]
+use unicode_width::UnicodeWidthStr;
 
 use crate::color::OpalineColor;
 #[cfg(feature = "gradients")]
-use crate::gradient::Gradient;
+use crate::gradient::{Gradient, GradientWrap};
 use crate::style::OpalineStyle;
 use crate::theme::Theme;
 
@@ -123,6 +125,50 @@ impl Theme {
             Line::raw(content.to_string())
         }
     }
+
+    /// Like [`gradient_text`](Self::gradient_text), with the gradient
+    /// scrolled by `phase`. See [`gradient_spans_at_phase`].
+    #[cfg(feature = "gradients")]
+    pub fn gradient_text_at_phase(
+        &self,
+        gradient_name: &str,
+        content: &str,
+        phase: f32,
+    ) -> Line<'static> {
+        if let Some(gradient) = self.get_gradient(gradient_name) {
+            Line::from(gradient_spans_at_phase(content, gradient, phase))
+        } else {
+            Line::raw(content.to_string())
+        }
+    }
+
+    /// Color multi-line content along a named gradient. See
+    /// [`gradient_multiline`]. Returns the text unstyled if the gradient
+    /// doesn't exist.
+    #[cfg(feature = "gradients")]
+    pub fn gradient_multiline(
+        &self,
+        gradient_name: &str,
+        content: &str,
+        direction: GradientDirection,
+    ) -> Text<'static> {
+        if let Some(gradient) = self.get_gradient(gradient_name) {
+            gradient_multiline(content, gradient, direction)
+        } else {
+            Text::raw(content.to_string())
+        }
+    }
+
+    /// A [`GradientFill`] along a named gradient. Renders nothing if the
+    /// gradient doesn't exist.
+    #[cfg(feature = "gradients")]
+    pub fn gradient_fill(&self, gradient_name: &str) -> GradientFill<'_> {
+        GradientFill {
+            gradient: self.get_gradient(gradient_name),
+            direction: GradientDirection::Horizontal,
+            half_blocks: false,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -133,36 +179,57 @@ impl Theme {
 #[cfg(feature = "gradients")]
 pub fn gradient_spans(text: &str, gradient: &Gradient) -> Vec<Span<'static>> {
     let graphemes: Vec<&str> = text.graphemes(true).collect();
-    if graphemes.is_empty() {
-        return vec![];
-    }
-
     let colors = gradient.generate(graphemes.len());
-    graphemes
-        .into_iter()
-        .zip(colors)
-        .map(|(grapheme, color)| {
-            Span::styled(
-                grapheme.to_string(),
-                Style::default().fg(Color::Rgb(color.r, color.g, color.b)),
-            )
-        })
-        .collect()
+    colored_spans(graphemes, colors)
+}
+
+/// Like [`gradient_spans`], with the gradient scrolled by `phase`.
+///
+/// The gradient loops seamlessly across the text (see
+/// [`GradientWrap::Repeat`]), and increasing `phase` moves the colors to
+/// the right; `1.0` is one full cycle. Drive it from a frame counter to
+/// animate:
+///
+/// ```rust,ignore
+/// let phase = frame_count as f32 / 60.0; // one cycle per 60 frames
+/// let title = Line::from(gradient_spans_at_phase("Loading…", &gradient, phase));
+/// ```
+#[cfg(feature = "gradients")]
+pub fn gradient_spans_at_phase(text: &str, gradient: &Gradient, phase: f32) -> Vec<Span<'static>> {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let colors = gradient.generate_at_phase(graphemes.len(), phase, GradientWrap::Repeat);
+    colored_spans(graphemes, colors)
 }
 
 /// Render a repeated character across `width` with gradient coloring.
 #[cfg(feature = "gradients")]
 pub fn gradient_line(width: usize, ch: char, gradient: &Gradient) -> Vec<Span<'static>> {
-    if width == 0 {
-        return vec![];
-    }
+    let symbol = ch.to_string();
+    colored_spans(vec![symbol.as_str(); width], gradient.generate(width))
+}
+
+/// Like [`gradient_line`], with the gradient scrolled by `phase`. See
+/// [`gradient_spans_at_phase`].
+#[cfg(feature = "gradients")]
+pub fn gradient_line_at_phase(
+    width: usize,
+    ch: char,
+    gradient: &Gradient,
+    phase: f32,
+) -> Vec<Span<'static>> {
+    let symbol = ch.to_string();
+    let colors = gradient.generate_at_phase(width, phase, GradientWrap::Repeat);
+    colored_spans(vec![symbol.as_str(); width], colors)
+}
 
-    let colors = gradient.generate(width);
-    colors
+#[cfg(feature = "gradients")]
+fn colored_spans(graphemes: Vec<&str>, colors: Vec<OpalineColor>) -> Vec<Span<'static>> {
+    graphemes
         .into_iter()
-        .map(|color| {
+        .zip(colors)
+        .map(|(grapheme, color)| {
             Span::styled(
-                ch.to_string(),
+                grapheme.to_string(),
                 Style::default().fg(Color::Rgb(color.r, color.g, color.b)),
             )
         })
@@ -180,3 +247,219 @@ pub fn gradient_text_line(text: &str, gradient: &Gradient) -> Line<'static> {
 pub fn gradient_bar(width: usize, ch: char, gradient: &Gradient) -> Line<'static> {
     Line::from(gradient_line(width, ch, gradient))
 }
+
+/// Render a repeated character bar with a gradient scrolled by `phase`,
+/// returning a `Line`. See [`gradient_spans_at_phase`].
+#[cfg(feature = "gradients")]
+pub fn gradient_bar_at_phase(
+    width: usize,
+    ch: char,
+    gradient: &Gradient,
+    phase: f32,
+) -> Line<'static> {
+    Line::from(gradient_line_at_phase(width, ch, gradient, phase))
+}
+
+/// Color a whole [`Text`] along a gradient, across columns, lines, or both.
+///
+/// Positions are measured in display columns, so a wide CJK character or
+/// emoji advances the gradient by two cells instead of one, and each line
+/// is sampled at its row. The result matches a [`GradientFill`] in the same
+/// direction over the text's bounding box, with columns counted from the
+/// start of each line. Only the foreground changes; span, line, and text
+/// styles are kept.
+///
+/// ```rust,ignore
+/// let banner = gradient_multiline(BANNER, &gradient, GradientDirection::Diagonal);
+/// frame.render_widget(Paragraph::new(banner), area);
+/// ```
+#[cfg(feature = "gradients")]
+pub fn gradient_multiline<'a>(
+    text: impl Into<Text<'a>>,
+    gradient: &Gradient,
+    direction: GradientDirection,
+) -> Text<'static> {
+    let text = text.into();
+    let clamp = |n: usize| u16::try_from(n).unwrap_or(u16::MAX);
+    let width = clamp(text.lines.iter().map(Line::width).max().unwrap_or(0));
+    let height = clamp(text.lines.len());
+
+    let mut lines = Vec::with_capacity(text.lines.len());
+    for (y, line) in text.lines.iter().enumerate() {
+        let y = clamp(y);
+        let mut x = 0_u16;
+        let mut spans = Vec::new();
+        for span in &line.spans {
+            for grapheme in span.content.graphemes(true) {
+                let color = gradient.at(direction.position(x, y, width, height));
+                spans.push(Span::styled(
+                    grapheme.to_string(),
+                    span.style.fg(color.into()),
+                ));
+                x = x.saturating_add(clamp(grapheme.width()));
+            }
+        }
+        lines.push(Line {
+            spans,
+            style: line.style,
+            alignment: line.alignment,
+        });
+    }
+    Text {
+        lines,
+        style: text.style,
+        alignment: text.alignment,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Gradient fills
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Which way a [`GradientFill`] or [`gradient_multiline`] runs.
+#[cfg(feature = "gradients")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// Left to right.
+    #[default]
+    Horizontal,
+    /// Top to bottom.
+    Vertical,
+    /// Top-left to bottom-right.
+    Diagonal,
+    /// Center outward; the corners get the last stop.
+    Radial,
+}
+
+#[cfg(feature = "gradients")]
+impl GradientDirection {
+    /// Position along the gradient for point `(x, y)` of a `width × height`
+    /// grid.
+    fn position(self, x: u16, y: u16, width: u16, height: u16) -> f32 {
+        let along = |i: u16, len: u16| {
+            if len > 1 {
+                f32::from(i) / f32::from(len - 1)
+            } else {
+                0.0
+            }
+        };
+        let (u, v) = (along(x, width), along(y, height));
+        match self {
+            Self::Horizontal => u,
+            Self::Vertical => v,
+            Self::Diagonal => f32::midpoint(u, v),
+            Self::Radial => {
+                let (dx, dy) = (u.mul_add(2.0, -1.0), v.mul_add(2.0, -1.0));
+                (dx.hypot(dy) / std::f32::consts::SQRT_2).min(1.0)
+            }
+        }
+    }
+}
+
+/// A widget that paints an area's background with a gradient.
+///
+/// Only cell backgrounds change, so it can go under or over other widgets
+/// without clobbering their text. With [`half_blocks`](Self::half_blocks),
+/// blank cells become `▀` with the upper sample as foreground and the lower
+/// as background, doubling vertical resolution; cells with content keep
+/// their symbol and get the average of the two.
+///
+/// ```rust,ignore
+/// use opaline::{GradientDirection, GradientFill};
+///
+/// frame.render_widget(theme.gradient_fill("aurora").direction(GradientDirection::Radial), area);
+/// frame.render_widget(Paragraph::new("Hello"), area);
+/// ```
+#[cfg(feature = "gradients")]
+#[derive(Debug, Clone, Copy)]
+pub struct GradientFill<'a> {
+    gradient: Option<&'a Gradient>,
+    direction: GradientDirection,
+    half_blocks: bool,
+}
+
+#[cfg(feature = "gradients")]
+impl<'a> GradientFill<'a> {
+    /// A horizontal fill along `gradient`.
+    pub const fn new(gradient: &'a Gradient) -> Self {
+        Self {
+            gradient: Some(gradient),
+            direction: GradientDirection::Horizontal,
+            half_blocks: false,
+        }
+    }
+
+    /// Set the direction. Defaults to [`GradientDirection::Horizontal`].
+    #[must_use]
+    pub const fn direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Use `▀` half blocks on blank cells for twice the vertical resolution.
+    #[must_use]
+    pub const fn half_blocks(mut self, half_blocks: bool) -> Self {
+        self.half_blocks = half_blocks;
+        self
+    }
+}
+
+#[cfg(feature = "gradients")]
+impl ratatui_core::widgets::Widget for GradientFill<'_> {
+    fn render(self, area: ratatui_core::layout::Rect, buf: &mut ratatui_core::buffer::Buffer) {
+        let Some(gradient) = self.gradient else {
+            return;
+        };
+        let area = area.intersection(buf.area);
+        let rows = if self.half_blocks {
+            area.height.saturating_mul(2)
+        } else {
+            area.height
+        };
+        let at = |x: u16, row: u16| self.direction.position(x, row, area.width, rows);
+
+        for dy in 0..area.height {
+            for dx in 0..area.width {
+                let Some(cell) = buf.cell_mut((area.x + dx, area.y + dy)) else {
+                    continue;
+                };
+                if !self.half_blocks {
+                    cell.set_bg(Color::from(gradient.at(at(dx, dy))));
+                    continue;
+                }
+                // Saturate so areas over 32767 rows tall don't overflow.
+                let row = dy.saturating_mul(2);
+                let (upper, lower) = (at(dx, row), at(dx, row.saturating_add(1)));
+                if cell.symbol() == " " {
+                    cell.set_symbol("\u{2580}");
+                    cell.set_fg(Color::from(gradient.at(upper)));
+                    cell.set_bg(Color::from(gradient.at(lower)));
+                } else {
+                    cell.set_bg(Color::from(gradient.at(f32::midpoint(upper, lower))));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "gradients")]
+impl ratatui_core::widgets::Widget for &GradientFill<'_> {
+    fn render(self, area: ratatui_core::layout::Rect, buf: &mut ratatui_core::buffer::Buffer) {
+        ratatui_core::widgets::Widget::render(*self, area, buf);
+    }
+}
+
+/// Paint the background of `area` along `gradient`. See [`GradientFill`].
+#[cfg(feature = "gradients")]
+pub fn fill_gradient(
+    buf: &mut ratatui_core::buffer::Buffer,
+    area: ratatui_core::layout::Rect,
+    gradient: &Gradient,
+    direction: GradientDirection,
+) {
+    ratatui_core::widgets::Widget::render(
+        GradientFill::new(gradient).direction(direction),
+        area,
+        buf,
+    );
+}
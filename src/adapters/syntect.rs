@@ -11,6 +11,9 @@
 //! let syntect_theme = to_syntect_theme(&theme);
 //! // Use with syntect's HighlightLines, html module, etc.
 //! ```
+//!
+//! With the `syntect-highlight` feature, [`Theme::highlight_code`] runs the
+//! whole pipeline and returns a ratatui [`Text`](ratatui_core::text::Text).
 
 use syntect::highlighting::{
     Color, FontStyle, ScopeSelectors, StyleModifier, Theme as SyntectTheme, ThemeItem,
@@ -90,7 +93,7 @@ fn style_to_modifier(s: &OpalineStyle) -> StyleModifier {
 // Theme generation
 // ═══════════════════════════════════════════════════════════════════════════════
 
-/// Mapping from Opaline token names to `TextMate` scope selectors.
+/// Default mapping from Opaline token names to `TextMate` scope selectors.
 const SCOPE_MAPPINGS: &[(&str, &str)] = &[
     ("code.keyword", "keyword"),
     ("code.string", "string"),
@@ -108,13 +111,126 @@ const SCOPE_MAPPINGS: &[(&str, &str)] = &[
     ("info", "string.other.link, support.constant"),
 ];
 
+/// An ordered mapping from `TextMate` scope selectors to Opaline names.
+///
+/// Each target is looked up as a token (or palette name) first, then as a
+/// style. Token targets pick up the modifiers of the matching style, so
+/// `code.keyword` inherits `bold` from a `keyword` style.
+///
+/// ```rust,ignore
+/// use opaline::adapters::syntect::{ScopeMapping, to_syntect_theme_with};
+///
+/// let theme = opaline::Theme::default();
+/// let mapping = ScopeMapping::for_theme(&theme)
+///     .with("keyword.control", "accent.secondary")
+///     .with("markup.heading", "keyword");
+/// let syntect_theme = to_syntect_theme_with(&theme, &mapping);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeMapping {
+    entries: Vec<(String, String)>,
+}
+
+impl ScopeMapping {
+    /// Create an empty mapping with no scopes.
+    ///
+    /// Use [`ScopeMapping::default`] for the built-in table instead.
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// The default mapping merged with the theme's `[syntax]` table.
+    ///
+    /// Theme entries replace defaults with the same selector and are
+    /// appended (sorted by selector) otherwise.
+    pub fn for_theme(theme: &Theme) -> Self {
+        let mut mapping = Self::default();
+        let mut overrides: Vec<(&String, &String)> = theme.syntax_mapping().iter().collect();
+        overrides.sort_unstable();
+        for (scope, target) in overrides {
+            mapping.insert(scope.as_str(), target.as_str());
+        }
+        mapping
+    }
+
+    /// Map `scope` to `target`, replacing any existing entry for `scope`.
+    pub fn insert(&mut self, scope: impl Into<String>, target: impl Into<String>) {
+        let scope = scope.into();
+        let target = target.into();
+        if let Some(entry) = self.entries.iter_mut().find(|(s, _)| *s == scope) {
+            entry.1 = target;
+        } else {
+            self.entries.push((scope, target));
+        }
+    }
+
+    /// Builder-style [`insert`](Self::insert).
+    #[must_use]
+    pub fn with(mut self, scope: impl Into<String>, target: impl Into<String>) -> Self {
+        self.insert(scope, target);
+        self
+    }
+
+    /// Remove the entry for `scope`, returning its target.
+    pub fn remove(&mut self, scope: &str) -> Option<String> {
+        let pos = self.entries.iter().position(|(s, _)| s == scope)?;
+        Some(self.entries.remove(pos).1)
+    }
+
+    /// Look up the target mapped to `scope`.
+    pub fn get(&self, scope: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(s, _)| s == scope)
+            .map(|(_, t)| t.as_str())
+    }
+
+    /// Iterate `(scope, target)` pairs in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(s, t)| (s.as_str(), t.as_str()))
+    }
+
+    /// Number of mapped scopes.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the mapping has no scopes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for ScopeMapping {
+    /// The built-in token → scope table.
+    fn default() -> Self {
+        Self {
+            entries: SCOPE_MAPPINGS
+                .iter()
+                .map(|&(token, scope)| (scope.to_string(), token.to_string()))
+                .collect(),
+        }
+    }
+}
+
 /// Convert an Opaline [`Theme`] to a [`syntect::highlighting::Theme`].
 ///
 /// Maps theme metadata, token colors to `ThemeSettings`, and `code.*` tokens
-/// to `TextMate` scope selectors for syntax highlighting.
+/// to `TextMate` scope selectors for syntax highlighting. Entries in the
+/// theme's `[syntax]` table are merged over the default scope mapping.
 pub fn to_syntect_theme(theme: &Theme) -> SyntectTheme {
+    to_syntect_theme_with(theme, &ScopeMapping::for_theme(theme))
+}
+
+/// Convert an Opaline [`Theme`] using an explicit [`ScopeMapping`].
+///
+/// The mapping is used as-is; start from [`ScopeMapping::for_theme`] to keep
+/// the defaults and the theme's own overrides.
+pub fn to_syntect_theme_with(theme: &Theme, mapping: &ScopeMapping) -> SyntectTheme {
     let settings = build_settings(theme);
-    let scopes = build_scopes(theme);
+    let scopes = build_scopes(theme, mapping);
 
     SyntectTheme {
         name: Some(theme.meta.name.clone()),
@@ -143,24 +259,204 @@ fn build_settings(theme: &Theme) -> ThemeSettings {
     }
 }
 
-fn build_scopes(theme: &Theme) -> Vec<ThemeItem> {
+fn build_scopes(theme: &Theme, mapping: &ScopeMapping) -> Vec<ThemeItem> {
     let mut items = Vec::new();
 
-    for &(token, scope_str) in SCOPE_MAPPINGS {
-        if let Some(color) = theme.try_color(token) {
-            // Check if there's a corresponding style with modifiers
-            let style_name = token.strip_prefix("code.").unwrap_or(token);
-            let mut style = theme
-                .try_style(style_name)
-                .map(StyleModifier::from)
-                .unwrap_or_default();
-            style.foreground = Some(color.into());
-
-            if let Ok(scope) = scope_str.parse::<ScopeSelectors>() {
-                items.push(ThemeItem { scope, style });
-            }
+    for (scope_str, target) in mapping.iter() {
+        if let Some(style) = resolve_target(theme, target)
+            && let Ok(scope) = scope_str.parse::<ScopeSelectors>()
+        {
+            items.push(ThemeItem { scope, style });
         }
     }
 
     items
 }
+
+/// Resolve a mapping target: tokens (with matching style modifiers) first,
+/// then named styles. Returns `None` when the theme defines neither.
+fn resolve_target(theme: &Theme, target: &str) -> Option<StyleModifier> {
+    if let Some(color) = theme.try_color(target) {
+        // Check if there's a corresponding style with modifiers
+        let style_name = target.strip_prefix("code.").unwrap_or(target);
+        let mut style = theme
+            .try_style(style_name)
+            .map(StyleModifier::from)
+            .unwrap_or_default();
+        style.foreground = Some(color.into());
+        return Some(style);
+    }
+
+    theme.try_style(target).map(StyleModifier::from)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Ratatui highlighting
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "syntect-highlight")]
+mod highlight {
+    use std::sync::LazyLock;
+
+    use ratatui_core::style::{Color as RatatuiColor, Modifier, Style};
+    use ratatui_core::text::{Line, Span, Text};
+    use syntect::easy::HighlightLines;
+    use syntect::highlighting::{FontStyle, Style as SyntectStyle, Theme as SyntectTheme};
+    use syntect::parsing::{SyntaxReference, SyntaxSet};
+    use syntect::util::LinesWithEndings;
+
+    use crate::theme::Theme;
+
+    static DEFAULT_SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+    /// Options for [`Theme::highlight_code_with`].
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct HighlightOptions<'a> {
+        line_numbers: bool,
+        syntax_set: Option<&'a SyntaxSet>,
+        syntect_theme: Option<&'a SyntectTheme>,
+    }
+
+    impl<'a> HighlightOptions<'a> {
+        /// Default options: no gutter, syntect's bundled syntaxes.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Prefix each line with a right-aligned `code.line_number` gutter.
+        #[must_use]
+        pub fn line_numbers(mut self, enabled: bool) -> Self {
+            self.line_numbers = enabled;
+            self
+        }
+
+        /// Use a custom [`SyntaxSet`] instead of syntect's bundled syntaxes.
+        ///
+        /// The set must be built for newline-terminated lines (e.g.
+        /// [`SyntaxSet::load_defaults_newlines`]).
+        #[must_use]
+        pub fn syntax_set(mut self, syntax_set: &'a SyntaxSet) -> Self {
+            self.syntax_set = Some(syntax_set);
+            self
+        }
+
+        /// Highlight with a syntect theme built ahead of time by
+        /// [`to_syntect_theme`](super::to_syntect_theme), instead of
+        /// converting the theme on every call. Worth it when highlighting
+        /// many snippets with the same theme.
+        #[must_use]
+        pub fn syntect_theme(mut self, syntect_theme: &'a SyntectTheme) -> Self {
+            self.syntect_theme = Some(syntect_theme);
+            self
+        }
+    }
+
+    impl Theme {
+        /// Syntax-highlight `code` into a ratatui [`Text`].
+        ///
+        /// `syntax_name` is a file extension (`"rs"`) or syntax name
+        /// (`"Rust"`); unknown names fall back to plain text, and to
+        /// unhighlighted lines if the syntax set has no Plain Text syntax.
+        /// Every span gets the `bg.code` background when the theme defines it.
+        pub fn highlight_code(&self, code: &str, syntax_name: &str) -> Text<'static> {
+            self.highlight_code_with(code, syntax_name, &HighlightOptions::default())
+        }
+
+        /// Like [`highlight_code`](Self::highlight_code) with explicit
+        /// [`HighlightOptions`].
+        pub fn highlight_code_with(
+            &self,
+            code: &str,
+            syntax_name: &str,
+            options: &HighlightOptions<'_>,
+        ) -> Text<'static> {
+            let syntax_set = options.syntax_set.unwrap_or(&DEFAULT_SYNTAXES);
+            let converted;
+            let syntect_theme = if let Some(theme) = options.syntect_theme {
+                theme
+            } else {
+                converted = super::to_syntect_theme(self);
+                &converted
+            };
+            let mut highlighter = find_syntax(syntax_set, syntax_name)
+                .map(|syntax| HighlightLines::new(syntax, syntect_theme));
+
+            let code_bg = self.try_color("bg.code").map(RatatuiColor::from);
+            let base = code_bg.map_or_else(Style::default, |bg| Style::default().bg(bg));
+
+            let line_count = LinesWithEndings::from(code).count().max(1);
+            let gutter_width = line_count.to_string().len();
+            let gutter_style = self
+                .try_color("code.line_number")
+                .map_or(base, |fg| base.fg(fg.into()));
+
+            let mut lines = Vec::with_capacity(line_count);
+            for (i, raw_line) in LinesWithEndings::from(code).enumerate() {
+                let mut spans = Vec::new();
+                if options.line_numbers {
+                    spans.push(Span::styled(
+                        format!("{:>gutter_width$} ", i + 1),
+                        gutter_style,
+                    ));
+                }
+
+                let regions = highlighter
+                    .as_mut()
+                    .and_then(|h| h.highlight_line(raw_line, syntax_set).ok());
+                if let Some(regions) = regions {
+                    for (style, text) in regions {
+                        let text = text.trim_end_matches(['\n', '\r']);
+                        if !text.is_empty() {
+                            spans.push(Span::styled(
+                                text.to_string(),
+                                convert_style(style, code_bg),
+                            ));
+                        }
+                    }
+                } else {
+                    let text = raw_line.trim_end_matches(['\n', '\r']);
+                    spans.push(Span::styled(text.to_string(), base));
+                }
+
+                lines.push(Line::from(spans));
+            }
+
+            Text::from(lines).style(base)
+        }
+    }
+
+    /// `None` when neither `name` nor Plain Text is in the set; custom sets
+    /// don't have to include it.
+    fn find_syntax<'s>(syntax_set: &'s SyntaxSet, name: &str) -> Option<&'s SyntaxReference> {
+        syntax_set
+            .find_syntax_by_token(name)
+            .or_else(|| syntax_set.find_syntax_by_name(name))
+            .or_else(|| syntax_set.find_syntax_by_name("Plain Text"))
+    }
+
+    fn convert_style(style: SyntectStyle, code_bg: Option<RatatuiColor>) -> Style {
+        let fg = style.foreground;
+        let bg = style.background;
+        let mut out = Style::default()
+            .fg(RatatuiColor::Rgb(fg.r, fg.g, fg.b))
+            .bg(code_bg.unwrap_or(RatatuiColor::Rgb(bg.r, bg.g, bg.b)));
+
+        let mut modifiers = Modifier::empty();
+        if style.font_style.contains(FontStyle::BOLD) {
+            modifiers |= Modifier::BOLD;
+        }
+        if style.font_style.contains(FontStyle::ITALIC) {
+            modifiers |= Modifier::ITALIC;
+        }
+        if style.font_style.contains(FontStyle::UNDERLINE) {
+            modifiers |= Modifier::UNDERLINED;
+        }
+        if !modifiers.is_empty() {
+            out = out.add_modifier(modifiers);
+        }
+        out
+    }
+}
+
+#[cfg(feature = "syntect-highlight")]
+pub use highlight::HighlightOptions;
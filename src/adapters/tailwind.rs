@@ -0,0 +1,179 @@
+//! Tailwind CSS adapter — export a theme as a Tailwind color preset.
+//!
+//! Palette entries named `name_NNN` (`purple_500`, `gray_950`) are grouped
+//! into Tailwind color scales, so `purple_500` becomes `bg-purple-500`. Other
+//! palette entries become single colors, or the scale's `DEFAULT` when a
+//! scale of the same name exists.
+//!
+//! Semantic tokens become colors backed by the CSS custom properties from
+//! [`generate_css_vars`](super::css::generate_css_vars), so
+//! `accent.primary` becomes `text-accent-primary` with the value
+//! `var(--opaline-accent-primary)`. Ship those variables alongside the preset
+//! and the dashboard follows theme switches without rebuilding Tailwind.
+//! When a token and a palette entry produce the same key, the token wins.
+//!
+//! ```rust,ignore
+//! use opaline::adapters::tailwind;
+//!
+//! std::fs::write("opaline.preset.js", tailwind::to_tailwind_preset_js(&theme))?;
+//! std::fs::write("opaline.css", tailwind::to_tailwind_theme_css(&theme))?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value, json};
+
+use super::css::{CssOptions, css_ident};
+use crate::theme::Theme;
+
+/// A palette color or a scale of shades.
+enum ColorEntry {
+    Single(String),
+    Scale(BTreeMap<String, String>),
+}
+
+/// Build the Tailwind `colors` object for a theme.
+pub fn tailwind_colors(theme: &Theme) -> Value {
+    tailwind_colors_with(theme, &CssOptions::default())
+}
+
+/// [`tailwind_colors`] with custom CSS options. Only
+/// [`CssOptions::var_prefix`] is used, to name the token variables.
+pub fn tailwind_colors_with(theme: &Theme, options: &CssOptions) -> Value {
+    let mut colors = Map::new();
+    for (name, entry) in color_entries(theme, options) {
+        let value = match entry {
+            ColorEntry::Single(value) => json!(value),
+            ColorEntry::Scale(shades) => json!(shades),
+        };
+        colors.insert(name, value);
+    }
+    Value::Object(colors)
+}
+
+/// Export a Tailwind config fragment as pretty-printed JSON:
+/// `{ "theme": { "extend": { "colors": { ... } } } }`.
+pub fn to_tailwind_config(theme: &Theme) -> String {
+    to_tailwind_config_with(theme, &CssOptions::default())
+}
+
+/// [`to_tailwind_config`] with custom CSS options.
+pub fn to_tailwind_config_with(theme: &Theme, options: &CssOptions) -> String {
+    let config =
+        json!({ "theme": { "extend": { "colors": tailwind_colors_with(theme, options) } } });
+    serde_json::to_string_pretty(&config).expect("JSON values always serialize")
+}
+
+/// Export a Tailwind preset as a `module.exports` script, ready for the `presets`
+/// array of `tailwind.config.js`.
+pub fn to_tailwind_preset_js(theme: &Theme) -> String {
+    to_tailwind_preset_js_with(theme, &CssOptions::default())
+}
+
+/// [`to_tailwind_preset_js`] with custom CSS options.
+pub fn to_tailwind_preset_js_with(theme: &Theme, options: &CssOptions) -> String {
+    // The name comes from the theme file, so quote it as JSON (no raw
+    // newlines) and escape `*/` so it can't close the comment early.
+    let name = serde_json::to_string(&theme.meta.name)
+        .expect("strings always serialize")
+        .replace("*/", "*\\/");
+    format!(
+        "/* Generated by opaline from {name} */\nmodule.exports = {};\n",
+        to_tailwind_config_with(theme, options)
+    )
+}
+
+/// Export a Tailwind v4 `@theme inline` block.
+///
+/// Scales become `--color-purple-500`, single colors `--color-name`, and
+/// tokens `--color-accent-primary: var(--opaline-accent-primary)`. The block
+/// is `inline` so utilities use the `var()` reference directly and keep
+/// following the active theme.
+pub fn to_tailwind_theme_css(theme: &Theme) -> String {
+    to_tailwind_theme_css_with(theme, &CssOptions::default())
+}
+
+/// [`to_tailwind_theme_css`] with custom CSS options.
+pub fn to_tailwind_theme_css_with(theme: &Theme, options: &CssOptions) -> String {
+    let mut lines = vec!["@theme inline {".to_string()];
+
+    for (name, entry) in color_entries(theme, options) {
+        match entry {
+            ColorEntry::Single(value) => lines.push(format!("  --color-{name}: {value};")),
+            ColorEntry::Scale(shades) => {
+                // `DEFAULT` first, then shades in numeric order.
+                let mut shades: Vec<_> = shades.into_iter().collect();
+                shades.sort_by_key(|(shade, _)| shade.parse::<u32>().ok());
+                for (shade, value) in shades {
+                    let var_name = if shade == "DEFAULT" {
+                        format!("--color-{name}")
+                    } else {
+                        format!("--color-{name}-{shade}")
+                    };
+                    lines.push(format!("  {var_name}: {value};"));
+                }
+            }
+        }
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Palette entries grouped into scales, then tokens, keyed by Tailwind name.
+fn color_entries(theme: &Theme, options: &CssOptions) -> BTreeMap<String, ColorEntry> {
+    let mut entries: BTreeMap<String, ColorEntry> = BTreeMap::new();
+
+    let mut palette = theme.palette_names();
+    palette.sort_unstable();
+    let (scaled, single): (Vec<&str>, Vec<&str>) = palette
+        .into_iter()
+        .partition(|name| split_shade(name).is_some());
+
+    for name in scaled {
+        let Some((base, shade)) = split_shade(name) else {
+            continue;
+        };
+        let Some(color) = theme.palette_color(name) else {
+            continue;
+        };
+        let entry = entries
+            .entry(css_ident(base))
+            .or_insert_with(|| ColorEntry::Scale(BTreeMap::new()));
+        if let ColorEntry::Scale(shades) = entry {
+            shades.insert(shade.to_string(), color.to_hex());
+        }
+    }
+
+    for name in single {
+        let Some(color) = theme.palette_color(name) else {
+            continue;
+        };
+        let key = css_ident(name);
+        if let Some(ColorEntry::Scale(shades)) = entries.get_mut(&key) {
+            shades.insert("DEFAULT".to_string(), color.to_hex());
+        } else {
+            entries.insert(key, ColorEntry::Single(color.to_hex()));
+        }
+    }
+
+    for name in theme.token_names() {
+        let ident = css_ident(name);
+        let value = format!("var({})", options.var(&ident));
+        if let Some(ColorEntry::Scale(shades)) = entries.get_mut(&ident) {
+            shades.insert("DEFAULT".to_string(), value);
+        } else {
+            entries.insert(ident, ColorEntry::Single(value));
+        }
+    }
+
+    entries
+}
+
+/// Split `purple_500` into `("purple", "500")`.
+fn split_shade(name: &str) -> Option<(&str, &str)> {
+    let (base, shade) = name.rsplit_once('_')?;
+    let is_shade =
+        !base.is_empty() && !shade.is_empty() && shade.bytes().all(|b| b.is_ascii_digit());
+    is_shade.then_some((base, shade))
+}
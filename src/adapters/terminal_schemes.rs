@@ -0,0 +1,418 @@
+//! Terminal color scheme importers — pywal, Xresources, and iTerm2.
+//!
+//! Each importer reads the 16 ANSI colors plus background, foreground, and
+//! cursor into a [`ThemeFile`]:
+//!
+//! - `palette` — `color0` … `color15`, `background`, `foreground`, `cursor`
+//! - `tokens` — semantic tokens that reference those palette entries,
+//!   chosen by hue rather than by slot, so a scheme that puts orange in
+//!   `color1` still gets a red `error`
+//! - everything else the [`names`](crate::names) contract requires, derived
+//!   as in [`Theme::fill_missing`]
+//!
+//! ```rust,ignore
+//! use opaline::adapters::terminal_schemes;
+//!
+//! let theme_file = terminal_schemes::from_file("~/.cache/wal/colors.json")?;
+//! let theme = terminal_schemes::load_from_file("Dracula.itermcolors")?;
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::color::OpalineColor;
+use crate::error::OpalineError;
+use crate::names::tokens;
+use crate::resolver;
+use crate::schema::{ThemeFile, ThemeMeta, ThemeVariant};
+use crate::theme::Theme;
+
+/// The colors every terminal scheme defines.
+struct TerminalColors {
+    ansi: [OpalineColor; 16],
+    background: OpalineColor,
+    foreground: OpalineColor,
+    cursor: Option<OpalineColor>,
+}
+
+/// Semantic tokens mapped by hue, with the hue each one looks for.
+const HUE_TOKENS: &[(&str, f32)] = &[
+    (tokens::ERROR, 0.0),
+    (tokens::WARNING, 50.0),
+    (tokens::SUCCESS, 120.0),
+    (tokens::ACCENT_SECONDARY, 180.0),
+    (tokens::INFO, 220.0),
+    (tokens::ACCENT_PRIMARY, 300.0),
+];
+
+/// ANSI slots with a hue: red through cyan, normal and bright.
+const CHROMATIC_SLOTS: [usize; 12] = [1, 2, 3, 4, 5, 6, 9, 10, 11, 12, 13, 14];
+
+/// Colors below this HSL saturation are grays and never match a hue.
+const MIN_SATURATION: f32 = 0.2;
+
+/// Degrees added to bright slots, so the normal color wins unless the
+/// bright one is clearly closer in hue.
+const BRIGHT_PENALTY: f32 = 15.0;
+
+/// Colors below this contrast against the background only match when
+/// nothing readable is close in hue.
+const MIN_CONTRAST: f32 = 3.0;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// pywal
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Deserialize)]
+struct WalScheme {
+    special: WalSpecial,
+    colors: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct WalSpecial {
+    background: String,
+    foreground: String,
+    cursor: Option<String>,
+}
+
+/// Import a pywal `colors.json` (usually `~/.cache/wal/colors.json`).
+pub fn from_pywal_str(json: &str) -> Result<ThemeFile, OpalineError> {
+    const FORMAT: &str = "pywal";
+    let scheme: WalScheme =
+        serde_json::from_str(json).map_err(|e| invalid(FORMAT, e.to_string()))?;
+
+    let color = |key: &str, value: &str| {
+        OpalineColor::from_hex(value).map_err(|e| invalid(FORMAT, format!("{key}: {e}")))
+    };
+    let mut ansi = [OpalineColor::FALLBACK; 16];
+    for (i, slot) in ansi.iter_mut().enumerate() {
+        let key = format!("color{i}");
+        let value = scheme
+            .colors
+            .get(&key)
+            .ok_or_else(|| invalid(FORMAT, format!("missing '{key}'")))?;
+        *slot = color(&key, value)?;
+    }
+
+    build(
+        &TerminalColors {
+            ansi,
+            background: color("background", &scheme.special.background)?,
+            foreground: color("foreground", &scheme.special.foreground)?,
+            cursor: scheme
+                .special
+                .cursor
+                .as_deref()
+                .map(|value| color("cursor", value))
+                .transpose()?,
+        },
+        "pywal",
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Xresources
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Import X resources (`~/.Xresources`, `~/.Xdefaults`).
+///
+/// Resource names match on their last component, so `*.color1`,
+/// `*color1`, and `URxvt.color1` all set `color1`. `#define` names are
+/// substituted, and colors can be `#rrggbb`, `#rgb`, or `rgb:rr/gg/bb`.
+/// The cursor comes from `cursorColor`.
+pub fn from_xresources_str(text: &str) -> Result<ThemeFile, OpalineError> {
+    const FORMAT: &str = "Xresources";
+    let mut defines: HashMap<&str, &str> = HashMap::new();
+    let mut resources: HashMap<&str, OpalineColor> = HashMap::new();
+
+    for line in text.lines().map(str::trim) {
+        if let Some(define) = line.strip_prefix("#define") {
+            let mut parts = define.split_whitespace();
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                defines.insert(name, value);
+            }
+            continue;
+        }
+        if line.is_empty() || line.starts_with('!') || line.starts_with('#') {
+            continue;
+        }
+        let Some((resource, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = resource
+            .rsplit(['.', '*'])
+            .next()
+            .unwrap_or(resource)
+            .trim();
+        if !is_scheme_resource(name) {
+            continue;
+        }
+        let value = value.trim();
+        let value = defines.get(value).copied().unwrap_or(value);
+        let color = parse_x_color(value)
+            .ok_or_else(|| invalid(FORMAT, format!("{name}: invalid color '{value}'")))?;
+        resources.insert(name, color);
+    }
+
+    let get = |name: &str| {
+        resources
+            .get(name)
+            .copied()
+            .ok_or_else(|| invalid(FORMAT, format!("missing '{name}'")))
+    };
+    let mut ansi = [OpalineColor::FALLBACK; 16];
+    for (i, slot) in ansi.iter_mut().enumerate() {
+        *slot = get(&format!("color{i}"))?;
+    }
+
+    build(
+        &TerminalColors {
+            ansi,
+            background: get("background")?,
+            foreground: get("foreground")?,
+            cursor: resources.get("cursorColor").copied(),
+        },
+        "Xresources",
+    )
+}
+
+fn is_scheme_resource(name: &str) -> bool {
+    matches!(name, "background" | "foreground" | "cursorColor")
+        || name
+            .strip_prefix("color")
+            .and_then(|n| n.parse::<u8>().ok())
+            .is_some_and(|n| n < 16)
+}
+
+/// `#rrggbb`, `#rgb`, or `rgb:r/g/b` with 1–4 hex digits per channel.
+fn parse_x_color(value: &str) -> Option<OpalineColor> {
+    if let Some(channels) = value.strip_prefix("rgb:") {
+        let mut parts = channels.split('/').map(scale_hex_channel);
+        let color = OpalineColor::new(parts.next()??, parts.next()??, parts.next()??);
+        return parts.next().is_none().then_some(color);
+    }
+    let hex = value.strip_prefix('#')?;
+    if hex.len() == 3 {
+        let mut digits = hex.chars().map(|c| c.to_digit(16));
+        let mut next = || u8::try_from(digits.next()?? * 17).ok();
+        return Some(OpalineColor::new(next()?, next()?, next()?));
+    }
+    OpalineColor::from_hex(value).ok()
+}
+
+/// Scale an X11 channel of 1–4 hex digits to 8 bits.
+fn scale_hex_channel(digits: &str) -> Option<u8> {
+    if digits.is_empty() || digits.len() > 4 {
+        return None;
+    }
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    let max = (1u32 << (4 * digits.len())) - 1;
+    u8::try_from((value * 255 + max / 2) / max).ok()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// iTerm2
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Import an iTerm2 `.itermcolors` property list.
+///
+/// Components are read as sRGB. Colors saved in iTerm2's P3 or calibrated
+/// spaces import slightly off, as they do in most other tools.
+pub fn from_iterm_str(xml: &str) -> Result<ThemeFile, OpalineError> {
+    const FORMAT: &str = "iTerm2";
+    // Exported plists always carry Apple's DOCTYPE.
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..roxmltree::ParsingOptions::default()
+    };
+    let document = roxmltree::Document::parse_with_options(xml, options)
+        .map_err(|e| invalid(FORMAT, e.to_string()))?;
+    let root = document
+        .root_element()
+        .children()
+        .find(|node| node.has_tag_name("dict"))
+        .ok_or_else(|| invalid(FORMAT, "missing top-level <dict>"))?;
+    let colors: HashMap<&str, roxmltree::Node<'_, '_>> = plist_entries(root).collect();
+
+    let get = |key: &str| {
+        let dict = colors
+            .get(key)
+            .ok_or_else(|| invalid(FORMAT, format!("missing '{key}'")))?;
+        iterm_color(*dict).ok_or_else(|| invalid(FORMAT, format!("{key}: invalid color")))
+    };
+    let mut ansi = [OpalineColor::FALLBACK; 16];
+    for (i, slot) in ansi.iter_mut().enumerate() {
+        *slot = get(&format!("Ansi {i} Color"))?;
+    }
+
+    build(
+        &TerminalColors {
+            ansi,
+            background: get("Background Color")?,
+            foreground: get("Foreground Color")?,
+            cursor: colors
+                .get("Cursor Color")
+                .and_then(|&dict| iterm_color(dict)),
+        },
+        "iTerm2",
+    )
+}
+
+/// `<key>`/value pairs of a plist `<dict>`.
+fn plist_entries<'a, 'input>(
+    dict: roxmltree::Node<'a, 'input>,
+) -> impl Iterator<Item = (&'a str, roxmltree::Node<'a, 'input>)> {
+    let mut elements = dict.children().filter(roxmltree::Node::is_element);
+    std::iter::from_fn(move || {
+        let key = elements.next()?;
+        let value = elements.next()?;
+        Some((key.text().unwrap_or_default(), value))
+    })
+    .filter(|(_, value)| value.has_tag_name("dict"))
+}
+
+#[allow(
+    clippy::cast_sign_loss,
+    clippy::cast_possible_truncation,
+    clippy::as_conversions
+)]
+fn iterm_color(dict: roxmltree::Node<'_, '_>) -> Option<OpalineColor> {
+    let mut elements = dict.children().filter(roxmltree::Node::is_element);
+    let (mut r, mut g, mut b) = (None, None, None);
+    while let (Some(key), Some(value)) = (elements.next(), elements.next()) {
+        let component = value.text().and_then(|t| t.trim().parse::<f32>().ok());
+        match key.text() {
+            Some("Red Component") => r = component,
+            Some("Green Component") => g = component,
+            Some("Blue Component") => b = component,
+            _ => {}
+        }
+    }
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Some(OpalineColor::new(channel(r?), channel(g?), channel(b?)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Files
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Import a scheme file, picking the format from its name: `.json` is
+/// pywal, `.itermcolors` is iTerm2, and anything else is read as
+/// Xresources. The theme is named after the file stem.
+pub fn from_file(path: impl AsRef<Path>) -> Result<ThemeFile, OpalineError> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|source| OpalineError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let mut theme_file = if extension.eq_ignore_ascii_case("json") {
+        from_pywal_str(&contents)?
+    } else if extension.eq_ignore_ascii_case("itermcolors") {
+        from_iterm_str(&contents)?
+    } else {
+        from_xresources_str(&contents)?
+    };
+    if let Some(stem) = path.file_stem() {
+        theme_file.meta.name = stem.to_string_lossy().into_owned();
+    }
+    Ok(theme_file)
+}
+
+/// Import a scheme file with [`from_file`] and resolve it into a [`Theme`].
+pub fn load_from_file(path: impl AsRef<Path>) -> Result<Theme, OpalineError> {
+    let theme_file = from_file(path)?;
+    let resolved = resolver::resolve(&theme_file)?;
+    Ok(Theme::from_resolved(theme_file.meta, resolved))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Mapping
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Build a contract-complete theme file. Mapped tokens reference palette
+/// entries; derived names are written as literal colors.
+fn build(colors: &TerminalColors, name: &str) -> Result<ThemeFile, OpalineError> {
+    let variant = if colors.background.relative_luminance() < colors.foreground.relative_luminance()
+    {
+        ThemeVariant::Dark
+    } else {
+        ThemeVariant::Light
+    };
+
+    let mut palette: HashMap<String, String> = colors
+        .ansi
+        .iter()
+        .enumerate()
+        .map(|(i, color)| (format!("color{i}"), color.to_hex()))
+        .collect();
+    palette.insert("background".to_string(), colors.background.to_hex());
+    palette.insert("foreground".to_string(), colors.foreground.to_hex());
+    palette.insert(
+        "cursor".to_string(),
+        colors.cursor.unwrap_or(colors.foreground).to_hex(),
+    );
+
+    let mut token_map: HashMap<String, String> = HashMap::from([
+        (tokens::BG_BASE.to_string(), "background".to_string()),
+        (tokens::TEXT_PRIMARY.to_string(), "foreground".to_string()),
+    ]);
+    for &(token, hue) in HUE_TOKENS {
+        if let Some(slot) = closest_slot(colors, hue) {
+            token_map.insert(token.to_string(), format!("color{slot}"));
+        }
+    }
+
+    let imported = ThemeFile {
+        meta: ThemeMeta {
+            variant,
+            ..ThemeMeta::new(name)
+        },
+        palette,
+        tokens: token_map,
+        styles: HashMap::new(),
+        gradients: HashMap::new(),
+        syntax: HashMap::new(),
+    };
+
+    let resolved = resolver::resolve(&imported)?;
+    let mut theme = Theme::from_resolved(imported.meta, resolved);
+    theme.fill_missing();
+    Ok(theme.to_theme_file())
+}
+
+/// The chromatic ANSI slot nearest in hue, preferring readable colors and
+/// normal over bright.
+fn closest_slot(colors: &TerminalColors, hue: f32) -> Option<usize> {
+    CHROMATIC_SLOTS
+        .iter()
+        .filter_map(|&slot| {
+            let color = colors.ansi[slot];
+            let (h, s, _) = color.to_hsl();
+            if s < MIN_SATURATION {
+                return None;
+            }
+            let d = (h - hue).rem_euclid(360.0);
+            let mut score = d.min(360.0 - d);
+            if slot > 8 {
+                score += BRIGHT_PENALTY;
+            }
+            if color.contrast_ratio(colors.background) < MIN_CONTRAST {
+                score += 90.0;
+            }
+            Some((score, slot))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, slot)| slot)
+}
+
+fn invalid(format: &str, message: impl Into<String>) -> OpalineError {
+    OpalineError::InvalidFormat {
+        format: format.to_string(),
+        message: message.into(),
+    }
+}
@@ -0,0 +1,172 @@
+//! Tree-sitter adapter — resolve highlight capture names to theme styles.
+//!
+//! Apps using `tree-sitter-highlight` register an ordered list of highlight
+//! names (`keyword.function`, `string.escape`, ...) and receive events that
+//! refer to them by index. [`highlight_styles`] returns a parallel
+//! `Vec<OpalineStyle>` for that list, so each `Highlight(i)` maps straight to
+//! `styles[i]`.
+//!
+//! Names resolve through dotted-prefix fallback: `keyword.function` tries
+//! `keyword.function`, then `keyword`. At each level a named style wins, then
+//! a `code.*` token, then a small table of common capture aliases.
+//!
+//! ```rust,ignore
+//! use opaline::adapters::tree_sitter::{HighlightEvent, highlight_lines, highlight_styles};
+//!
+//! let names = ["keyword", "function.method", "string.escape"];
+//! let styles = highlight_styles(&theme, &names);
+//!
+//! let events = highlighter
+//!     .highlight(&config, source.as_bytes(), None, |_| None)?
+//!     .map(|event| match event? {
+//!         tree_sitter_highlight::HighlightEvent::Source { start, end } => {
+//!             Ok(HighlightEvent::Source { start, end })
+//!         }
+//!         tree_sitter_highlight::HighlightEvent::HighlightStart(h) => {
+//!             Ok(HighlightEvent::HighlightStart(h.0))
+//!         }
+//!         tree_sitter_highlight::HighlightEvent::HighlightEnd => Ok(HighlightEvent::HighlightEnd),
+//!     })
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! let lines = highlight_lines(source, events, &styles);
+//! ```
+
+#[cfg(feature = "ratatui")]
+use ratatui_core::style::Style;
+#[cfg(feature = "ratatui")]
+use ratatui_core::text::{Line, Span};
+
+use crate::style::OpalineStyle;
+use crate::theme::Theme;
+
+/// Fallback tokens for common capture names that have no `code.*` token of
+/// their own.
+const HIGHLIGHT_ALIASES: &[(&str, &str)] = &[
+    ("attribute", "code.type"),
+    ("boolean", "code.number"),
+    ("constant", "accent.tertiary"),
+    ("constructor", "code.type"),
+    ("embedded", "text.primary"),
+    ("escape", "accent.tertiary"),
+    ("float", "code.number"),
+    ("label", "accent.tertiary"),
+    ("method", "code.function"),
+    ("module", "code.type"),
+    ("namespace", "code.type"),
+    ("operator", "accent.secondary"),
+    ("property", "accent.primary"),
+    ("punctuation", "text.secondary"),
+    ("tag", "code.keyword"),
+    ("variable", "text.primary"),
+];
+
+/// A highlight event, mirroring `tree_sitter_highlight::HighlightEvent`.
+///
+/// Keeping a local copy avoids pinning a tree-sitter version; converting is a
+/// one-line `match` (see the [module docs](self)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightEvent {
+    /// A byte range of source text, styled by the innermost open highlight.
+    Source { start: usize, end: usize },
+    /// A highlight begins. The index refers to the registered names list.
+    HighlightStart(usize),
+    /// The innermost open highlight ends.
+    HighlightEnd,
+}
+
+/// Resolve a single capture name to a style.
+///
+/// A leading `@` is ignored. Returns an empty style when nothing matches at
+/// any prefix level.
+pub fn highlight_style(theme: &Theme, name: &str) -> OpalineStyle {
+    let mut name = name.strip_prefix('@').unwrap_or(name);
+
+    loop {
+        if let Some(style) = resolve_level(theme, name) {
+            return style;
+        }
+        match name.rsplit_once('.') {
+            Some((parent, _)) => name = parent,
+            None => return OpalineStyle::default(),
+        }
+    }
+}
+
+/// Resolve an ordered list of capture names into a parallel list of styles.
+pub fn highlight_styles<S: AsRef<str>>(theme: &Theme, names: &[S]) -> Vec<OpalineStyle> {
+    names
+        .iter()
+        .map(|name| highlight_style(theme, name.as_ref()))
+        .collect()
+}
+
+/// Try one prefix level: named style → `code.*` token → alias table.
+fn resolve_level(theme: &Theme, name: &str) -> Option<OpalineStyle> {
+    if let Some(style) = theme.try_style(name) {
+        return Some(style.clone());
+    }
+    if let Some(color) = theme.try_color(&format!("code.{name}")) {
+        return Some(OpalineStyle::fg(color));
+    }
+    HIGHLIGHT_ALIASES
+        .iter()
+        .find(|&&(alias, _)| alias == name)
+        .and_then(|&(_, token)| theme.try_color(token))
+        .map(OpalineStyle::fg)
+}
+
+/// Render highlight events over `source` into one ratatui [`Line`] per
+/// source line.
+///
+/// `styles` is the list returned by [`highlight_styles`]; out-of-range
+/// highlight indices and out-of-range byte ranges are ignored.
+#[cfg(feature = "ratatui")]
+pub fn highlight_lines<I>(source: &str, events: I, styles: &[OpalineStyle]) -> Vec<Line<'static>>
+where
+    I: IntoIterator<Item = HighlightEvent>,
+{
+    let mut lines = Vec::new();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut stack: Vec<usize> = Vec::new();
+
+    for event in events {
+        match event {
+            HighlightEvent::HighlightStart(idx) => stack.push(idx),
+            HighlightEvent::HighlightEnd => {
+                stack.pop();
+            }
+            HighlightEvent::Source { start, end } => {
+                let Some(text) = source.get(start..end) else {
+                    continue;
+                };
+                let style = stack
+                    .last()
+                    .and_then(|&idx| styles.get(idx))
+                    .map(Style::from)
+                    .unwrap_or_default();
+
+                let mut parts = text.split('\n');
+                if let Some(first) = parts.next() {
+                    push_span(&mut spans, first, style);
+                }
+                for part in parts {
+                    lines.push(Line::from(std::mem::take(&mut spans)));
+                    push_span(&mut spans, part, style);
+                }
+            }
+        }
+    }
+
+    if !spans.is_empty() || lines.is_empty() {
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+#[cfg(feature = "ratatui")]
+fn push_span(spans: &mut Vec<Span<'static>>, text: &str, style: Style) {
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    if !text.is_empty() {
+        spans.push(Span::styled(text.to_string(), style));
+    }
+}
@@ -0,0 +1,490 @@
+//! `opaline` — list, preview, validate, export, and scaffold themes.
+//!
+//! Built with the `bin` feature:
+//!
+//! ```sh
+//! cargo install opaline --features bin
+//! opaline list
+//! opaline show nord
+//! opaline validate my-theme.toml
+//! opaline export nord --format css -o nord.css
+//! opaline new "My Theme" --variant light -o my-theme.toml
+//! ```
+
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use colored::Colorize;
+use opaline::adapters::{css, dtcg, preview, tailwind};
+use opaline::names::{gradients, styles, tokens};
+use opaline::{ColoredExt, Contract, Theme, ThemeCliExt, ThemeFile, ThemeVariant, resolver};
+
+const USAGE: &str = "\
+Usage: opaline <command> [options]
+
+Commands:
+  list                          List builtin and discovered themes
+  show <theme>                  Preview a theme's palette, tokens, styles, and gradients
+  validate <file>               Check a theme file and report every problem
+  export <theme> --format <f>   Export a theme (css, scss, less, tailwind,
+                                tailwind-json, tailwind-v4, dtcg, html, svg, toml)
+  new [name]                    Scaffold a theme file that satisfies the contract
+
+Options:
+  -f, --format <format>         Export format
+  -o, --output <file>           Write to a file instead of stdout
+      --variant <dark|light>    Variant for `new` (default: dark)
+      --force                   Let `new` overwrite an existing --output file
+  -h, --help                    Show this help
+
+<theme> is a builtin or discovered theme id, or a path to a .toml file.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("{} {message}", "error:".theme_fg(ERROR_RED).bold());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Status color for the CLI's own messages, independent of any theme.
+const ERROR_RED: opaline::OpalineColor = opaline::OpalineColor::new(0xff, 0x63, 0x63);
+const WARNING_YELLOW: opaline::OpalineColor = opaline::OpalineColor::new(0xf1, 0xfa, 0x8c);
+const SUCCESS_GREEN: opaline::OpalineColor = opaline::OpalineColor::new(0x50, 0xfa, 0x7b);
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    let Some((command, rest)) = args.split_first() else {
+        println!("{USAGE}");
+        return Ok(ExitCode::FAILURE);
+    };
+    let options = Options::parse(rest)?;
+    if options.help {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    match command.as_str() {
+        "list" => list(),
+        "show" => show(&options),
+        "validate" => validate(&options),
+        "export" => export(&options),
+        "new" => new(&options),
+        "-h" | "--help" | "help" => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        }
+        other => Err(format!("unknown command `{other}`\n\n{USAGE}")),
+    }
+}
+
+// ── Argument parsing ────────────────────────────────────────────────────
+
+/// Flags and positional arguments shared by every subcommand.
+#[derive(Default)]
+struct Options {
+    positional: Vec<String>,
+    format: Option<String>,
+    output: Option<PathBuf>,
+    variant: Option<String>,
+    force: bool,
+    help: bool,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("`{flag}` needs a value"))
+            };
+            match arg.as_str() {
+                "-f" | "--format" => options.format = Some(value(arg)?),
+                "-o" | "--output" => options.output = Some(PathBuf::from(value(arg)?)),
+                "--variant" => options.variant = Some(value(arg)?),
+                "--force" => options.force = true,
+                "-h" | "--help" => options.help = true,
+                flag if flag.starts_with('-') && flag.len() > 1 => {
+                    return Err(format!("unknown option `{flag}`"));
+                }
+                _ => options.positional.push(arg.clone()),
+            }
+        }
+        Ok(options)
+    }
+
+    fn required(&self, what: &str) -> Result<&str, String> {
+        self.positional
+            .first()
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing <{what}>\n\n{USAGE}"))
+    }
+
+    /// Print to stdout, or write to `--output` when given.
+    fn emit(&self, contents: &str) -> Result<(), String> {
+        if let Some(path) = &self.output {
+            return std::fs::write(path, contents)
+                .map_err(|e| format!("cannot write {}: {e}", path.display()));
+        }
+
+        let newline = if contents.ends_with('\n') { "" } else { "\n" };
+        match write!(std::io::stdout().lock(), "{contents}{newline}") {
+            // `opaline export … | head` closing the pipe early is fine.
+            Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                Err(format!("cannot write to stdout: {e}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Load a theme by id (builtin or discovered) or from a `.toml` path.
+fn load_theme(name: &str) -> Result<Theme, String> {
+    let path = Path::new(name);
+    if path.extension().is_some_and(|ext| ext == "toml") || path.is_file() {
+        return opaline::load_from_file(path).map_err(|e| e.to_string());
+    }
+    opaline::list_available_themes()
+        .into_iter()
+        .find(|info| info.name == name)
+        .and_then(|info| info.load())
+        .or_else(|| opaline::load_by_name(name))
+        .ok_or_else(|| format!("no theme named `{name}` (try `opaline list`)"))
+}
+
+// ── list ────────────────────────────────────────────────────────────────
+
+#[allow(clippy::unnecessary_wraps)]
+fn list() -> Result<ExitCode, String> {
+    let themes = opaline::list_available_themes();
+    let width = themes.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for info in &themes {
+        let source = match &info.path {
+            Some(path) => path.display().to_string(),
+            None => "builtin".to_string(),
+        };
+        println!(
+            "{:<width$}  {:<5}  {}  {}",
+            info.name,
+            info.variant.to_string(),
+            info.display_name,
+            source.dimmed()
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// ── show ────────────────────────────────────────────────────────────────
+
+fn show(options: &Options) -> Result<ExitCode, String> {
+    let theme = load_theme(options.required("theme")?)?;
+
+    let title = format!("{} ({})", theme.meta.name, theme.meta.variant);
+    println!("{}", theme.cli_gradient(&title, gradients::PRIMARY));
+    if let Some(description) = &theme.meta.description {
+        println!("{}", theme.cli_colored(description, tokens::TEXT_MUTED));
+    }
+
+    section(&theme, "Palette");
+    let mut palette = theme.palette_names();
+    palette.sort_unstable();
+    let width = palette.iter().map(|n| n.len()).max().unwrap_or(0);
+    for name in palette {
+        let color = theme.palette_color(name).unwrap_or_default();
+        println!(
+            "  {}  {name:<width$}  {}",
+            "    ".theme_bg(color),
+            color.to_hex()
+        );
+    }
+
+    section(&theme, "Tokens");
+    let mut token_names = theme.token_names();
+    token_names.sort_unstable();
+    let width = token_names.iter().map(|n| n.len()).max().unwrap_or(0);
+    for name in token_names {
+        let color = theme.color(name);
+        let source = theme
+            .token_reference(name)
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+        println!(
+            "  {}  {:<width$}  {}",
+            "    ".theme_bg(color),
+            name.theme_fg(color),
+            source.dimmed()
+        );
+    }
+
+    section(&theme, "Styles");
+    let mut style_names = theme.style_names();
+    style_names.sort_unstable();
+    for name in style_names {
+        println!("  {}", name.theme_style(&theme.style(name)));
+    }
+
+    section(&theme, "Gradients");
+    let mut gradient_names = theme.gradient_names();
+    gradient_names.sort_unstable();
+    let width = gradient_names.iter().map(|n| n.len()).max().unwrap_or(0);
+    let bar = "█".repeat(40);
+    for name in gradient_names {
+        println!("  {name:<width$}  {}", theme.cli_gradient(&bar, name));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn section(theme: &Theme, title: &str) {
+    println!();
+    println!(
+        "{}",
+        theme.cli_colored(title, tokens::ACCENT_PRIMARY).bold()
+    );
+}
+
+// ── validate ────────────────────────────────────────────────────────────
+
+fn validate(options: &Options) -> Result<ExitCode, String> {
+    let path = Path::new(options.required("file")?);
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let theme_file: ThemeFile =
+        toml::from_str(&source).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    let errors = resolver::validate(&theme_file);
+    for error in &errors {
+        println!("{} {error}", "error:".theme_fg(ERROR_RED).bold());
+    }
+
+    let missing = Contract::standard().check_file(&theme_file).missing();
+    for name in &missing {
+        println!(
+            "{} missing {name}",
+            "warning:".theme_fg(WARNING_YELLOW).bold()
+        );
+    }
+
+    let summary = format!(
+        "{}: {} error(s), {} missing contract name(s)",
+        path.display(),
+        errors.len(),
+        missing.len()
+    );
+    if errors.is_empty() {
+        println!("{}", summary.theme_fg(SUCCESS_GREEN));
+        Ok(ExitCode::SUCCESS)
+    } else {
+        println!("{}", summary.theme_fg(ERROR_RED));
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+// ── export ──────────────────────────────────────────────────────────────
+
+fn export(options: &Options) -> Result<ExitCode, String> {
+    let theme = load_theme(options.required("theme")?)?;
+    let format = options
+        .format
+        .as_deref()
+        .ok_or_else(|| "`export` needs --format".to_string())?;
+
+    let output = match format {
+        "css" => css::generate_stylesheet(&theme),
+        "scss" => css::generate_scss_maps(&theme, "opaline"),
+        "less" => css::generate_less_maps(&theme, "opaline"),
+        "tailwind" => tailwind::to_tailwind_preset_js(&theme),
+        "tailwind-json" => tailwind::to_tailwind_config(&theme),
+        "tailwind-v4" => tailwind::to_tailwind_theme_css(&theme),
+        "dtcg" => dtcg::theme_to_dtcg_string(&theme),
+        "html" => preview::render_html(&theme),
+        "svg" => preview::render_svg(&theme),
+        "toml" => theme_toml(&theme)?,
+        other => return Err(format!("unknown export format `{other}`")),
+    };
+
+    options.emit(&output)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The theme as TOML with sorted keys and `[meta]` first.
+fn theme_toml(theme: &Theme) -> Result<String, String> {
+    let mut body = toml::Table::try_from(theme.to_theme_file()).map_err(|e| e.to_string())?;
+    let mut head = toml::Table::new();
+    if let Some(meta) = body.remove("meta") {
+        head.insert("meta".to_string(), meta);
+    }
+    Ok(format!("{head}\n{body}"))
+}
+
+// ── new ─────────────────────────────────────────────────────────────────
+
+fn new(options: &Options) -> Result<ExitCode, String> {
+    let name = options
+        .positional
+        .first()
+        .map_or("My Theme", String::as_str);
+    let variant = match options.variant.as_deref() {
+        None | Some("dark") => ThemeVariant::Dark,
+        Some("light") => ThemeVariant::Light,
+        Some(other) => return Err(format!("unknown variant `{other}` (dark or light)")),
+    };
+
+    if let Some(path) = &options.output {
+        if path.exists() && !options.force {
+            return Err(format!(
+                "{} already exists (pass --force to overwrite)",
+                path.display()
+            ));
+        }
+    }
+
+    options.emit(&scaffold(name, variant))?;
+    if let Some(path) = &options.output {
+        eprintln!("Wrote {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Starter palettes: `(name, dark, light)`.
+const SCAFFOLD_PALETTE: &[(&str, &str, &str)] = &[
+    ("bg", "#1a1a2e", "#fafafa"),
+    ("bg_alt", "#24243a", "#ececf1"),
+    ("fg", "#e0e0e0", "#2e2e3a"),
+    ("muted", "#6a6a7a", "#8a8a99"),
+    ("accent", "#e135ff", "#a100c8"),
+    ("secondary", "#80ffea", "#00857a"),
+    ("red", "#ff6363", "#d32f2f"),
+    ("green", "#50fa7b", "#2e7d32"),
+    ("yellow", "#f1fa8c", "#b58900"),
+    ("blue", "#80bfff", "#1565c0"),
+    ("orange", "#ffb86c", "#e65100"),
+];
+
+/// Palette entry for every contract token.
+const SCAFFOLD_TOKENS: &[(&str, &str)] = &[
+    (tokens::TEXT_PRIMARY, "fg"),
+    (tokens::TEXT_SECONDARY, "fg"),
+    (tokens::TEXT_MUTED, "muted"),
+    (tokens::TEXT_DIM, "muted"),
+    (tokens::BG_BASE, "bg"),
+    (tokens::BG_PANEL, "bg_alt"),
+    (tokens::BG_CODE, "bg_alt"),
+    (tokens::BG_HIGHLIGHT, "bg_alt"),
+    (tokens::BG_SELECTION, "bg_alt"),
+    (tokens::ACCENT_PRIMARY, "accent"),
+    (tokens::ACCENT_SECONDARY, "secondary"),
+    (tokens::ACCENT_TERTIARY, "blue"),
+    (tokens::ACCENT_DEEP, "accent"),
+    (tokens::SUCCESS, "green"),
+    (tokens::ERROR, "red"),
+    (tokens::WARNING, "yellow"),
+    (tokens::INFO, "blue"),
+    (tokens::BORDER_FOCUSED, "accent"),
+    (tokens::BORDER_UNFOCUSED, "muted"),
+    (tokens::CODE_KEYWORD, "accent"),
+    (tokens::CODE_FUNCTION, "secondary"),
+    (tokens::CODE_STRING, "green"),
+    (tokens::CODE_NUMBER, "orange"),
+    (tokens::CODE_COMMENT, "muted"),
+    (tokens::CODE_TYPE, "yellow"),
+    (tokens::CODE_LINE_NUMBER, "muted"),
+];
+
+/// Every contract style as `(style, fg token, bg token, bold)`.
+const SCAFFOLD_STYLES: &[(&str, &str, Option<&str>, bool)] = &[
+    (styles::KEYWORD, tokens::ACCENT_PRIMARY, None, true),
+    (styles::LINE_NUMBER, tokens::CODE_LINE_NUMBER, None, false),
+    (
+        styles::SELECTED,
+        tokens::ACCENT_SECONDARY,
+        Some(tokens::BG_HIGHLIGHT),
+        false,
+    ),
+    (
+        styles::ACTIVE_SELECTED,
+        tokens::ACCENT_PRIMARY,
+        Some(tokens::BG_HIGHLIGHT),
+        true,
+    ),
+    (styles::FOCUSED_BORDER, tokens::BORDER_FOCUSED, None, false),
+    (
+        styles::UNFOCUSED_BORDER,
+        tokens::BORDER_UNFOCUSED,
+        None,
+        false,
+    ),
+    (styles::SUCCESS_STYLE, tokens::SUCCESS, None, false),
+    (styles::ERROR_STYLE, tokens::ERROR, None, false),
+    (styles::WARNING_STYLE, tokens::WARNING, None, false),
+    (styles::INFO_STYLE, tokens::INFO, None, false),
+    (styles::DIMMED, tokens::TEXT_DIM, None, false),
+    (styles::MUTED, tokens::TEXT_MUTED, None, false),
+    (
+        styles::INLINE_CODE,
+        tokens::SUCCESS,
+        Some(tokens::BG_CODE),
+        false,
+    ),
+];
+
+/// Palette entries for every contract gradient.
+const SCAFFOLD_GRADIENTS: &[(&str, &[&str])] = &[
+    (gradients::PRIMARY, &["accent", "secondary"]),
+    (gradients::WARM, &["orange", "yellow"]),
+    (gradients::SUCCESS_GRADIENT, &["green", "secondary"]),
+    (gradients::ERROR_GRADIENT, &["red", "orange"]),
+    (
+        gradients::AURORA,
+        &["accent", "secondary", "green", "blue", "accent"],
+    ),
+];
+
+/// A theme file defining every token, style, and gradient in the contract.
+fn scaffold(name: &str, variant: ThemeVariant) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "[meta]");
+    let _ = writeln!(out, "name = {}", toml::Value::from(name));
+    let _ = writeln!(out, "variant = \"{}\"", variant.to_string().to_lowercase());
+    let _ = writeln!(out, "version = \"1.0\"");
+
+    let _ = writeln!(out, "\n[palette]");
+    for &(entry, dark, light) in SCAFFOLD_PALETTE {
+        let hex = if variant == ThemeVariant::Light {
+            light
+        } else {
+            dark
+        };
+        let _ = writeln!(out, "{entry} = \"{hex}\"");
+    }
+
+    let _ = writeln!(out, "\n[tokens]");
+    for (token, entry) in SCAFFOLD_TOKENS {
+        let _ = writeln!(out, "\"{token}\" = \"{entry}\"");
+    }
+
+    let _ = writeln!(out, "\n[styles]");
+    for &(style, fg, bg, bold) in SCAFFOLD_STYLES {
+        let mut fields = vec![format!("fg = \"{fg}\"")];
+        fields.extend(bg.map(|bg| format!("bg = \"{bg}\"")));
+        if bold {
+            fields.push("bold = true".to_string());
+        }
+        let _ = writeln!(out, "{style} = {{ {} }}", fields.join(", "));
+    }
+
+    let _ = writeln!(out, "\n[gradients]");
+    for (gradient, stops) in SCAFFOLD_GRADIENTS {
+        let stops: Vec<String> = stops.iter().map(|stop| format!("\"{stop}\"")).collect();
+        let _ = writeln!(out, "{gradient} = [{}]", stops.join(", "));
+    }
+    out
+}
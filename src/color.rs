@@ -119,6 +119,127 @@ impl OpalineColor {
             .round() as u8;
         self.lerp(Self::new(lum, lum, lum), amount)
     }
+
+    /// WCAG 2 relative luminance, from 0.0 (black) to 1.0 (white).
+    pub fn relative_luminance(self) -> f32 {
+        0.2126f32.mul_add(
+            srgb_to_linear(self.r),
+            0.7152f32.mul_add(srgb_to_linear(self.g), 0.0722 * srgb_to_linear(self.b)),
+        )
+    }
+
+    /// WCAG 2 contrast ratio against `other`, from 1.0 to 21.0.
+    ///
+    /// Body text needs 4.5 for level AA and 7.0 for AAA.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Hue in degrees (`0.0..360.0`), saturation and lightness (`0.0..=1.0`).
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let r = f32::from(self.r) / 255.0;
+        let g = f32::from(self.g) / 255.0;
+        let b = f32::from(self.b) / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = f32::midpoint(max, min);
+        let delta = max - min;
+        if delta <= f32::EPSILON {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - 2.0f32.mul_add(l, -1.0).abs());
+        let h = if (max - r).abs() <= f32::EPSILON {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if (max - g).abs() <= f32::EPSILON {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        ((h * 60.0).rem_euclid(360.0), s.clamp(0.0, 1.0), l)
+    }
+
+    /// Build a color from hue in degrees (wrapped), saturation, and lightness
+    /// (both clamped to `0.0..=1.0`).
+    #[allow(
+        clippy::cast_sign_loss,
+        clippy::cast_possible_truncation,
+        clippy::as_conversions,
+        clippy::many_single_char_names
+    )]
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let c = (1.0 - 2.0f32.mul_add(l, -1.0).abs()) * s;
+        let x = c * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let channel = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::new(channel(r), channel(g), channel(b))
+    }
+
+    /// Oklab lightness (`0.0..=1.0`) and the `a` (green–red) and `b`
+    /// (blue–yellow) axes. Distances in Oklab track perceived differences,
+    /// which makes it the right space for clustering and chroma comparisons.
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let [r, g, b] = [self.r, self.g, self.b].map(srgb_to_linear);
+        let l = (0.051_457_565 * b + 0.536_332_55 * g + 0.412_221_46 * r).cbrt();
+        let m = (0.107_406_58 * b + 0.680_699_5 * g + 0.211_903_5 * r).cbrt();
+        let s = (0.629_978_7 * b + 0.281_718_85 * g + 0.088_302_46 * r).cbrt();
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Build a color from Oklab coordinates, clamping to the sRGB gamut.
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        Self::new(
+            linear_to_srgb(4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_),
+            linear_to_srgb(-1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_),
+            linear_to_srgb(-0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_),
+        )
+    }
+}
+
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = f32::from(c) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[allow(
+    clippy::cast_sign_loss,
+    clippy::cast_possible_truncation,
+    clippy::as_conversions
+)]
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
 }
 
 impl Default for OpalineColor {
@@ -0,0 +1,238 @@
+//! Theme contracts — the tokens, styles, and gradients a theme must define.
+//!
+//! [`Contract::standard`] is the contract every builtin satisfies, built from
+//! the [`names`](crate::names) constants. Apps that read their own semantic
+//! names add them on top, so a user theme that forgets one is reported
+//! instead of quietly rendering [`OpalineColor::FALLBACK`](crate::OpalineColor::FALLBACK):
+//!
+//! ```rust
+//! use opaline::{Contract, Theme};
+//!
+//! let contract = Contract::standard().require_tokens(["git.added", "git.removed"]);
+//! let report = contract.check(&Theme::default());
+//! assert!(report.missing_tokens.contains(&"git.added".to_string()));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::names;
+use crate::schema::ThemeFile;
+use crate::theme::Theme;
+
+/// Names a theme is required to define.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contract {
+    tokens: Vec<String>,
+    styles: Vec<String>,
+    gradients: Vec<String>,
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl Contract {
+    /// The standard contract from [`names`]: 26 tokens, 13 styles, and
+    /// 5 gradients.
+    pub fn standard() -> Self {
+        let owned = |all: &[&str]| all.iter().map(ToString::to_string).collect();
+        Self {
+            tokens: owned(names::tokens::ALL),
+            styles: owned(names::styles::ALL),
+            gradients: owned(names::gradients::ALL),
+        }
+    }
+
+    /// A contract that requires nothing, for apps that don't use the
+    /// standard names at all.
+    pub fn empty() -> Self {
+        Self {
+            tokens: Vec::new(),
+            styles: Vec::new(),
+            gradients: Vec::new(),
+        }
+    }
+
+    /// Also require a token.
+    #[must_use]
+    pub fn require_token(self, name: impl Into<String>) -> Self {
+        self.require_tokens([name])
+    }
+
+    /// Also require several tokens.
+    #[must_use]
+    pub fn require_tokens<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        extend_unique(&mut self.tokens, names);
+        self
+    }
+
+    /// Also require a style.
+    #[must_use]
+    pub fn require_style(mut self, name: impl Into<String>) -> Self {
+        extend_unique(&mut self.styles, [name]);
+        self
+    }
+
+    /// Also require a gradient.
+    #[must_use]
+    pub fn require_gradient(mut self, name: impl Into<String>) -> Self {
+        extend_unique(&mut self.gradients, [name]);
+        self
+    }
+
+    /// Required token names, in the order they were added.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    /// Required style names, in the order they were added.
+    pub fn styles(&self) -> &[String] {
+        &self.styles
+    }
+
+    /// Required gradient names, in the order they were added.
+    pub fn gradients(&self) -> &[String] {
+        &self.gradients
+    }
+
+    /// Compare a resolved theme against the contract.
+    ///
+    /// Without the `gradients` feature a theme has no gradients to check, so
+    /// required gradients are neither missing nor extra.
+    pub fn check(&self, theme: &Theme) -> ContractReport {
+        #[cfg(feature = "gradients")]
+        let gradients = theme.gradient_names();
+        #[cfg(not(feature = "gradients"))]
+        let gradients: Vec<&str> = self.gradients.iter().map(String::as_str).collect();
+
+        self.report(
+            &theme.token_names(),
+            &theme.palette_names(),
+            &theme.style_names(),
+            &gradients,
+        )
+    }
+
+    /// Compare a parsed theme file against the contract, without resolving it.
+    ///
+    /// As with [`check`](Self::check), a `[palette]` entry named like a
+    /// required token satisfies it.
+    pub fn check_file(&self, theme_file: &ThemeFile) -> ContractReport {
+        self.report(
+            &keys(&theme_file.tokens),
+            &keys(&theme_file.palette),
+            &keys(&theme_file.styles),
+            &keys(&theme_file.gradients),
+        )
+    }
+
+    /// A palette entry satisfies a required token, the same way
+    /// [`Theme::color`] falls back to the palette, but never counts as extra.
+    fn report(
+        &self,
+        tokens: &[&str],
+        palette: &[&str],
+        styles: &[&str],
+        gradients: &[&str],
+    ) -> ContractReport {
+        let (missing_tokens, extra_tokens) = diff(&self.tokens, tokens, palette);
+        let (missing_styles, extra_styles) = diff(&self.styles, styles, &[]);
+        let (missing_gradients, extra_gradients) = diff(&self.gradients, gradients, &[]);
+        ContractReport {
+            missing_tokens,
+            missing_styles,
+            missing_gradients,
+            extra_tokens,
+            extra_styles,
+            extra_gradients,
+        }
+    }
+}
+
+fn keys<V>(map: &HashMap<String, V>) -> Vec<&str> {
+    map.keys().map(String::as_str).collect()
+}
+
+fn extend_unique<I, S>(list: &mut Vec<String>, names: I)
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    for name in names {
+        let name = name.into();
+        if !list.contains(&name) {
+            list.push(name);
+        }
+    }
+}
+
+/// Required names in neither `defined` nor `fallback` (in contract order),
+/// and `defined` names that aren't required (sorted).
+fn diff(required: &[String], defined: &[&str], fallback: &[&str]) -> (Vec<String>, Vec<String>) {
+    let missing = required
+        .iter()
+        .filter(|name| !defined.contains(&name.as_str()) && !fallback.contains(&name.as_str()))
+        .cloned()
+        .collect();
+    let mut extra: Vec<String> = defined
+        .iter()
+        .filter(|name| !required.iter().any(|r| r == *name))
+        .map(ToString::to_string)
+        .collect();
+    extra.sort_unstable();
+    (missing, extra)
+}
+
+/// The result of checking a theme against a [`Contract`].
+///
+/// Missing names break the contract. Extra names are informational: themes
+/// are free to define more than they're required to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractReport {
+    pub missing_tokens: Vec<String>,
+    pub missing_styles: Vec<String>,
+    pub missing_gradients: Vec<String>,
+    pub extra_tokens: Vec<String>,
+    pub extra_styles: Vec<String>,
+    pub extra_gradients: Vec<String>,
+}
+
+impl ContractReport {
+    /// Whether every required name is defined.
+    pub fn is_satisfied(&self) -> bool {
+        self.missing_tokens.is_empty()
+            && self.missing_styles.is_empty()
+            && self.missing_gradients.is_empty()
+    }
+
+    /// Every missing name, qualified by kind: `token 'text.dim'`.
+    pub fn missing(&self) -> Vec<String> {
+        let qualify = |kind: &'static str, names: &[String]| {
+            names
+                .iter()
+                .map(move |name| format!("{kind} '{name}'"))
+                .collect::<Vec<_>>()
+        };
+        let mut all = qualify("token", &self.missing_tokens);
+        all.extend(qualify("style", &self.missing_styles));
+        all.extend(qualify("gradient", &self.missing_gradients));
+        all
+    }
+}
+
+/// Lists the missing names; extras are left out.
+impl fmt::Display for ContractReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_satisfied() {
+            return f.write_str("contract satisfied");
+        }
+        write!(f, "missing {}", self.missing().join(", "))
+    }
+}
@@ -0,0 +1,314 @@
+//! Derivation rules for filling in missing contract names.
+//!
+//! Each contract token has a list of rules, tried in order; the first one
+//! whose inputs exist wins. Tokens are derived repeatedly until nothing more
+//! can be derived, so chains like `bg.base → bg.code → inline_code` work no
+//! matter which names a theme starts with. Fixed colors are a last resort and
+//! are applied one at a time, so a single fixed root can still seed every
+//! token that derives from it.
+
+use crate::color::OpalineColor;
+use crate::contract::{Contract, ContractReport};
+#[cfg(feature = "gradients")]
+use crate::gradient::Gradient;
+#[cfg(feature = "gradients")]
+use crate::names::gradients;
+use crate::names::{styles, tokens};
+use crate::style::OpalineStyle;
+use crate::theme::Theme;
+
+/// One way to derive a token.
+enum Rule {
+    /// The same color as another token.
+    Copy(&'static str),
+    /// Move away from the background: lighter on dark themes, darker on
+    /// light ones.
+    Lift(&'static str, f32),
+    /// Move toward the background: darker on dark themes, lighter on light
+    /// ones.
+    Sink(&'static str, f32),
+    /// Mix `from` toward `to` by `t`.
+    Mix(&'static str, &'static str, f32),
+    /// A fixed color per variant, used only when nothing else applies.
+    Fixed {
+        dark: OpalineColor,
+        light: OpalineColor,
+    },
+}
+
+impl Rule {
+    fn derive(&self, theme: &Theme) -> Option<OpalineColor> {
+        let dark = theme.is_dark();
+        match *self {
+            Self::Copy(name) => theme.try_color(name),
+            Self::Lift(name, amount) => theme.try_color(name).map(|c| {
+                if dark {
+                    c.lighten(amount)
+                } else {
+                    c.darken(amount)
+                }
+            }),
+            Self::Sink(name, amount) => theme.try_color(name).map(|c| {
+                if dark {
+                    c.darken(amount)
+                } else {
+                    c.lighten(amount)
+                }
+            }),
+            Self::Mix(from, to, t) => Some(theme.try_color(from)?.lerp(theme.try_color(to)?, t)),
+            Self::Fixed { .. } => None,
+        }
+    }
+
+    fn fixed(&self, theme: &Theme) -> Option<OpalineColor> {
+        match *self {
+            Self::Fixed { dark, light } => Some(if theme.is_dark() { dark } else { light }),
+            _ => None,
+        }
+    }
+}
+
+const fn fixed(dark: [u8; 3], light: [u8; 3]) -> Rule {
+    Rule::Fixed {
+        dark: OpalineColor::new(dark[0], dark[1], dark[2]),
+        light: OpalineColor::new(light[0], light[1], light[2]),
+    }
+}
+
+/// Token rules. Roots come first so fixed fallbacks seed them before
+/// anything derived from them.
+const TOKEN_RULES: &[(&str, &[Rule])] = &[
+    (
+        tokens::TEXT_PRIMARY,
+        &[
+            Rule::Lift(tokens::BG_BASE, 0.85),
+            fixed([0xe0, 0xe0, 0xe0], [0x2e, 0x2e, 0x3a]),
+        ],
+    ),
+    (
+        tokens::BG_BASE,
+        &[
+            Rule::Sink(tokens::TEXT_PRIMARY, 0.85),
+            fixed([0x1a, 0x1a, 0x2e], [0xfa, 0xfa, 0xfa]),
+        ],
+    ),
+    (
+        tokens::ACCENT_PRIMARY,
+        &[
+            Rule::Copy(tokens::BORDER_FOCUSED),
+            Rule::Copy(tokens::CODE_KEYWORD),
+            fixed([0xe1, 0x35, 0xff], [0xa1, 0x00, 0xc8]),
+        ],
+    ),
+    (
+        tokens::SUCCESS,
+        &[
+            Rule::Copy(tokens::CODE_STRING),
+            fixed([0x50, 0xfa, 0x7b], [0x2e, 0x7d, 0x32]),
+        ],
+    ),
+    (
+        tokens::ERROR,
+        &[fixed([0xff, 0x63, 0x63], [0xd3, 0x2f, 0x2f])],
+    ),
+    (
+        tokens::WARNING,
+        &[
+            Rule::Copy(tokens::CODE_NUMBER),
+            fixed([0xf1, 0xfa, 0x8c], [0xb5, 0x89, 0x00]),
+        ],
+    ),
+    (
+        tokens::INFO,
+        &[
+            Rule::Copy(tokens::ACCENT_SECONDARY),
+            Rule::Copy(tokens::ACCENT_TERTIARY),
+            fixed([0x80, 0xbf, 0xff], [0x15, 0x65, 0xc0]),
+        ],
+    ),
+    // Text shades fade toward the background.
+    (
+        tokens::TEXT_SECONDARY,
+        &[Rule::Mix(tokens::TEXT_PRIMARY, tokens::BG_BASE, 0.2)],
+    ),
+    (
+        tokens::TEXT_MUTED,
+        &[Rule::Mix(tokens::TEXT_PRIMARY, tokens::BG_BASE, 0.45)],
+    ),
+    (
+        tokens::TEXT_DIM,
+        &[Rule::Mix(tokens::TEXT_PRIMARY, tokens::BG_BASE, 0.6)],
+    ),
+    // Surfaces step away from the base background.
+    (tokens::BG_PANEL, &[Rule::Lift(tokens::BG_BASE, 0.04)]),
+    (tokens::BG_CODE, &[Rule::Lift(tokens::BG_BASE, 0.06)]),
+    (tokens::BG_HIGHLIGHT, &[Rule::Lift(tokens::BG_BASE, 0.1)]),
+    (tokens::BG_SELECTION, &[Rule::Lift(tokens::BG_BASE, 0.15)]),
+    (
+        tokens::ACCENT_SECONDARY,
+        &[
+            Rule::Copy(tokens::CODE_FUNCTION),
+            Rule::Copy(tokens::INFO),
+            fixed([0x80, 0xff, 0xea], [0x00, 0x85, 0x7a]),
+        ],
+    ),
+    (
+        tokens::ACCENT_TERTIARY,
+        &[
+            Rule::Copy(tokens::CODE_TYPE),
+            Rule::Mix(tokens::ACCENT_PRIMARY, tokens::ACCENT_SECONDARY, 0.5),
+        ],
+    ),
+    (
+        tokens::ACCENT_DEEP,
+        &[Rule::Mix(tokens::ACCENT_PRIMARY, tokens::BG_BASE, 0.35)],
+    ),
+    (
+        tokens::BORDER_FOCUSED,
+        &[Rule::Copy(tokens::ACCENT_PRIMARY)],
+    ),
+    (
+        tokens::BORDER_UNFOCUSED,
+        &[Rule::Mix(tokens::BG_BASE, tokens::TEXT_PRIMARY, 0.25)],
+    ),
+    (tokens::CODE_KEYWORD, &[Rule::Copy(tokens::ACCENT_PRIMARY)]),
+    (
+        tokens::CODE_FUNCTION,
+        &[Rule::Copy(tokens::ACCENT_SECONDARY)],
+    ),
+    (tokens::CODE_STRING, &[Rule::Copy(tokens::SUCCESS)]),
+    (tokens::CODE_NUMBER, &[Rule::Copy(tokens::WARNING)]),
+    (tokens::CODE_COMMENT, &[Rule::Copy(tokens::TEXT_MUTED)]),
+    (tokens::CODE_TYPE, &[Rule::Copy(tokens::ACCENT_TERTIARY)]),
+    (tokens::CODE_LINE_NUMBER, &[Rule::Copy(tokens::TEXT_DIM)]),
+];
+
+/// Style rules: `(style, fg, bg, bold)`.
+const STYLE_RULES: &[(&str, Option<&str>, Option<&str>, bool)] = &[
+    (styles::KEYWORD, Some(tokens::CODE_KEYWORD), None, true),
+    (
+        styles::LINE_NUMBER,
+        Some(tokens::CODE_LINE_NUMBER),
+        None,
+        false,
+    ),
+    (
+        styles::SELECTED,
+        Some(tokens::ACCENT_SECONDARY),
+        Some(tokens::BG_HIGHLIGHT),
+        false,
+    ),
+    (
+        styles::ACTIVE_SELECTED,
+        Some(tokens::ACCENT_PRIMARY),
+        Some(tokens::BG_HIGHLIGHT),
+        true,
+    ),
+    (
+        styles::FOCUSED_BORDER,
+        Some(tokens::BORDER_FOCUSED),
+        None,
+        false,
+    ),
+    (
+        styles::UNFOCUSED_BORDER,
+        Some(tokens::BORDER_UNFOCUSED),
+        None,
+        false,
+    ),
+    (styles::SUCCESS_STYLE, Some(tokens::SUCCESS), None, false),
+    (styles::ERROR_STYLE, Some(tokens::ERROR), None, false),
+    (styles::WARNING_STYLE, Some(tokens::WARNING), None, false),
+    (styles::INFO_STYLE, Some(tokens::INFO), None, false),
+    (styles::DIMMED, Some(tokens::TEXT_DIM), None, false),
+    (styles::MUTED, Some(tokens::TEXT_MUTED), None, false),
+    (
+        styles::INLINE_CODE,
+        Some(tokens::SUCCESS),
+        Some(tokens::BG_CODE),
+        false,
+    ),
+];
+
+/// Gradient rules: stops by token name.
+#[cfg(feature = "gradients")]
+const GRADIENT_RULES: &[(&str, &[&str])] = &[
+    (
+        gradients::PRIMARY,
+        &[tokens::ACCENT_PRIMARY, tokens::ACCENT_SECONDARY],
+    ),
+    (gradients::WARM, &[tokens::WARNING, tokens::ERROR]),
+    (
+        gradients::SUCCESS_GRADIENT,
+        &[tokens::SUCCESS, tokens::ACCENT_SECONDARY],
+    ),
+    (gradients::ERROR_GRADIENT, &[tokens::ERROR, tokens::WARNING]),
+    (
+        gradients::AURORA,
+        &[
+            tokens::ACCENT_PRIMARY,
+            tokens::ACCENT_SECONDARY,
+            tokens::SUCCESS,
+            tokens::INFO,
+            tokens::ACCENT_PRIMARY,
+        ],
+    ),
+];
+
+/// Fill every missing standard-contract name. See [`Theme::fill_missing`].
+pub(crate) fn fill_missing(theme: &mut Theme) -> ContractReport {
+    let report = Contract::standard().check(theme);
+    if report.is_satisfied() {
+        return report;
+    }
+
+    loop {
+        derive_tokens(theme);
+        let seed = TOKEN_RULES
+            .iter()
+            .filter(|(token, _)| !theme.has_token(token))
+            .find_map(|(token, rules)| Some((*token, rules.iter().find_map(|r| r.fixed(theme))?)));
+        let Some((token, color)) = seed else {
+            break;
+        };
+        theme.register_token(token, color);
+    }
+
+    for &(name, fg, bg, bold) in STYLE_RULES {
+        let mut style = OpalineStyle::new();
+        style.fg = fg.and_then(|token| theme.try_color(token));
+        style.bg = bg.and_then(|token| theme.try_color(token));
+        style.bold = bold;
+        theme.register_default_style(name, style);
+    }
+
+    #[cfg(feature = "gradients")]
+    for &(name, stops) in GRADIENT_RULES {
+        let stops: Option<Vec<OpalineColor>> =
+            stops.iter().map(|token| theme.try_color(token)).collect();
+        if let Some(stops) = stops {
+            theme.register_default_gradient(name, Gradient::new(stops));
+        }
+    }
+
+    report
+}
+
+/// Apply non-fixed rules until nothing more can be derived.
+fn derive_tokens(theme: &mut Theme) {
+    loop {
+        let mut progress = false;
+        for &(token, rules) in TOKEN_RULES {
+            if theme.has_token(token) {
+                continue;
+            }
+            if let Some(color) = rules.iter().find_map(|rule| rule.derive(theme)) {
+                theme.register_token(token, color);
+                progress = true;
+            }
+        }
+        if !progress {
+            break;
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use crate::color::ColorParseError;
+use crate::contract::ContractReport;
 
 /// All errors that can occur during theme loading and resolution.
 #[derive(Debug, thiserror::Error)]
@@ -12,6 +13,13 @@ pub enum OpalineError {
         source: std::io::Error,
     },
 
+    #[error("I/O error writing {path}: {source}")]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("TOML parse error{}: {source}", path.as_ref().map(|p| format!(" in {}", p.display())).unwrap_or_default())]
     Parse {
         path: Option<PathBuf>,
@@ -40,4 +48,27 @@ pub enum OpalineError {
 
     #[error("gradient must have at least one color stop")]
     EmptyGradient,
+
+    #[error("invalid gradient '{name}': {source}")]
+    InvalidGradient {
+        name: String,
+        #[source]
+        source: Box<OpalineError>,
+    },
+
+    #[error("theme '{theme}' does not satisfy its contract: {report}")]
+    ContractViolation {
+        theme: String,
+        report: Box<ContractReport>,
+    },
+
+    #[error("could not decode image {path}: {source}")]
+    Image {
+        path: PathBuf,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    #[error("invalid {format} data: {message}")]
+    InvalidFormat { format: String, message: String },
 }
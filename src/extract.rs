@@ -0,0 +1,197 @@
+//! Dominant colors from images, for themes that match a wallpaper.
+//!
+//! Images are downscaled, bucketed into a weighted histogram, and clustered
+//! with k-means in Oklab, so colors that look alike end up in the same
+//! cluster. Seeding is deterministic: the same image always gives the same
+//! palette. Feed the result to [`ThemeBuilder::from_colors`], or use
+//! [`ThemeBuilder::from_image`] to do both steps at once.
+//!
+//! [`ThemeBuilder::from_colors`]: crate::ThemeBuilder::from_colors
+//! [`ThemeBuilder::from_image`]: crate::ThemeBuilder::from_image
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::color::OpalineColor;
+use crate::error::OpalineError;
+
+/// Number of colors [`ThemeBuilder::from_image`](crate::ThemeBuilder::from_image)
+/// extracts.
+pub const DEFAULT_COLORS: usize = 8;
+
+/// Images are downscaled to fit this size before clustering.
+const SAMPLE_SIZE: u32 = 128;
+
+/// Upper bound on k-means iterations; most images converge well before.
+const MAX_ITERATIONS: usize = 24;
+
+/// Decode a PNG or JPEG and return up to `count` dominant colors, most
+/// common first. Transparent pixels are ignored.
+pub fn palette_from_image(
+    path: impl AsRef<Path>,
+    count: usize,
+) -> Result<Vec<OpalineColor>, OpalineError> {
+    let path = path.as_ref();
+    let image = image::ImageReader::open(path)
+        .map_err(|source| OpalineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?
+        .with_guessed_format()
+        .map_err(|source| OpalineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?
+        .decode()
+        .map_err(|source| OpalineError::Image {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+    let image = if image.width() > SAMPLE_SIZE || image.height() > SAMPLE_SIZE {
+        image.thumbnail(SAMPLE_SIZE, SAMPLE_SIZE)
+    } else {
+        image
+    };
+    let pixels = image
+        .into_rgba8()
+        .pixels()
+        .filter(|p| p.0[3] >= 128)
+        .map(|p| OpalineColor::new(p.0[0], p.0[1], p.0[2]))
+        .collect::<Vec<_>>();
+    Ok(dominant_colors(pixels, count))
+}
+
+/// Cluster `pixels` into at most `count` colors, most common first.
+///
+/// Returns fewer colors when the input has fewer distinct ones, and none
+/// for empty input or a `count` of zero.
+pub fn dominant_colors(
+    pixels: impl IntoIterator<Item = OpalineColor>,
+    count: usize,
+) -> Vec<OpalineColor> {
+    let points = histogram(pixels);
+    let mut centroids = seed(&points, count);
+    if centroids.is_empty() {
+        return Vec::new();
+    }
+    let mut assignment = vec![usize::MAX; points.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (point, slot) in points.iter().zip(&mut assignment) {
+            let nearest = nearest(&centroids, point.lab);
+            if *slot != nearest {
+                *slot = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![([0.0f32; 3], 0.0f32); centroids.len()];
+        for (point, &cluster) in points.iter().zip(&assignment) {
+            let (sum, weight) = &mut sums[cluster];
+            for (s, v) in sum.iter_mut().zip(point.lab) {
+                *s += v * point.weight;
+            }
+            *weight += point.weight;
+        }
+        for (centroid, (sum, weight)) in centroids.iter_mut().zip(sums) {
+            if weight > 0.0 {
+                *centroid = sum.map(|s| s / weight);
+            }
+        }
+    }
+
+    let mut weights = vec![0.0f32; centroids.len()];
+    for (point, &cluster) in points.iter().zip(&assignment) {
+        weights[cluster] += point.weight;
+    }
+    let mut clusters: Vec<([f32; 3], f32)> = centroids
+        .into_iter()
+        .zip(weights)
+        .filter(|&(_, weight)| weight > 0.0)
+        .collect();
+    clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+    clusters
+        .into_iter()
+        .map(|([l, a, b], _)| OpalineColor::from_oklab(l, a, b))
+        .collect()
+}
+
+/// A histogram bucket: the mean color of its pixels, and how many there were.
+struct Point {
+    lab: [f32; 3],
+    weight: f32,
+}
+
+/// Bucket pixels by their top 5 bits per channel, which merges near-identical
+/// colors and keeps clustering fast on large images.
+#[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+fn histogram(pixels: impl IntoIterator<Item = OpalineColor>) -> Vec<Point> {
+    let mut buckets: HashMap<[u8; 3], ([u32; 3], u32)> = HashMap::new();
+    for pixel in pixels {
+        let (sum, n) = buckets
+            .entry([pixel.r >> 3, pixel.g >> 3, pixel.b >> 3])
+            .or_default();
+        for (s, c) in sum.iter_mut().zip([pixel.r, pixel.g, pixel.b]) {
+            *s += u32::from(c);
+        }
+        *n += 1;
+    }
+
+    // Sort so iteration order, and with it the result, never depends on
+    // the hash map.
+    let mut buckets: Vec<_> = buckets.into_iter().collect();
+    buckets.sort_unstable_by_key(|&(key, _)| key);
+    buckets
+        .into_iter()
+        .map(|(_, (sum, n))| {
+            let [red, green, blue] = sum.map(|s| u8::try_from(s / n).unwrap_or(u8::MAX));
+            let (l, a, b) = OpalineColor::new(red, green, blue).to_oklab();
+            Point {
+                lab: [l, a, b],
+                weight: n as f32,
+            }
+        })
+        .collect()
+}
+
+/// Deterministic seeding: start from the heaviest bucket, then repeatedly
+/// take the bucket that is heaviest relative to its distance from the
+/// centroids picked so far.
+fn seed(points: &[Point], count: usize) -> Vec<[f32; 3]> {
+    let mut centroids: Vec<[f32; 3]> = Vec::with_capacity(count);
+    while centroids.len() < count.min(points.len()) {
+        let next = points
+            .iter()
+            .map(|p| {
+                let distance = centroids
+                    .iter()
+                    .map(|&c| distance(c, p.lab))
+                    .fold(f32::INFINITY, f32::min);
+                (p.weight * distance.min(1.0), p.lab)
+            })
+            .filter(|&(score, _)| score > 0.0)
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((_, lab)) = next else {
+            break;
+        };
+        centroids.push(lab);
+    }
+    centroids
+}
+
+fn nearest(centroids: &[[f32; 3]], lab: [f32; 3]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .min_by(|a, b| distance(*a.1, lab).total_cmp(&distance(*b.1, lab)))
+        .map_or(0, |(i, _)| i)
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+}
@@ -0,0 +1,181 @@
+//! Theme generation from seed colors. See [`ThemeBuilder::from_seeds`].
+//!
+//! Accents are spread around the hue wheel from the seed accent, and the
+//! semantic colors keep their conventional hues (green, red, amber, blue) but
+//! take the accent's saturation so they sit in the same family. Every
+//! generated color is nudged until it's readable on the background. The
+//! remaining contract names come from the same rules as
+//! [`Theme::fill_missing`].
+
+use crate::color::OpalineColor;
+use crate::names::tokens;
+use crate::schema::ThemeVariant;
+use crate::theme::{Theme, ThemeBuilder};
+
+/// Hue offsets from the accent for the secondary and tertiary accents.
+const SECONDARY_OFFSET: f32 = 150.0;
+const TERTIARY_OFFSET: f32 = -40.0;
+
+/// Conventional hues for semantic colors.
+const SUCCESS_HUE: f32 = 140.0;
+const ERROR_HUE: f32 = 355.0;
+const WARNING_HUE: f32 = 42.0;
+const INFO_HUE: f32 = 205.0;
+
+/// Colors below this Oklab chroma are too gray to be an accent.
+const MIN_ACCENT_CHROMA: f32 = 0.04;
+
+/// Extracted accents must be at least this many degrees apart.
+const MIN_ACCENT_HUE_DISTANCE: f32 = 30.0;
+
+/// Accent used when none of the colors is colorful enough.
+const FALLBACK_ACCENT: OpalineColor = OpalineColor::new(0x7a, 0xa2, 0xf7);
+
+/// WCAG AA for body text.
+const MIN_CONTRAST: f32 = 4.5;
+
+pub(crate) fn from_seeds(
+    bg: OpalineColor,
+    fg: OpalineColor,
+    accent: OpalineColor,
+    variant: ThemeVariant,
+) -> ThemeBuilder {
+    from_accents(bg, fg, accent, &[], variant)
+}
+
+/// Like [`from_seeds`], with optional secondary and tertiary accents in
+/// `extra`. Missing ones are spread around the hue wheel as usual.
+fn from_accents(
+    bg: OpalineColor,
+    fg: OpalineColor,
+    accent: OpalineColor,
+    extra: &[OpalineColor],
+    variant: ThemeVariant,
+) -> ThemeBuilder {
+    let dark = variant == ThemeVariant::Dark;
+    let (accent_hue, accent_saturation, _) = accent.to_hsl();
+    let saturation = accent_saturation.clamp(0.45, 0.85);
+    let lightness = if dark { 0.68 } else { 0.42 };
+    let hue = |h: f32| readable(OpalineColor::from_hsl(h, saturation, lightness), bg, dark);
+
+    // Palette entry, token, color.
+    let seeds = [
+        ("bg", tokens::BG_BASE, bg),
+        ("fg", tokens::TEXT_PRIMARY, fg),
+        ("accent", tokens::ACCENT_PRIMARY, accent),
+        (
+            "secondary",
+            tokens::ACCENT_SECONDARY,
+            extra.first().map_or_else(
+                || hue(accent_hue + SECONDARY_OFFSET),
+                |&c| readable(c, bg, dark),
+            ),
+        ),
+        (
+            "tertiary",
+            tokens::ACCENT_TERTIARY,
+            extra.get(1).map_or_else(
+                || hue(accent_hue + TERTIARY_OFFSET),
+                |&c| readable(c, bg, dark),
+            ),
+        ),
+        ("green", tokens::SUCCESS, hue(SUCCESS_HUE)),
+        ("red", tokens::ERROR, hue(ERROR_HUE)),
+        ("yellow", tokens::WARNING, hue(WARNING_HUE)),
+        ("blue", tokens::INFO, hue(INFO_HUE)),
+    ];
+
+    let mut builder = Theme::builder("Generated").variant(variant);
+    for (entry, token, color) in seeds {
+        builder = builder.palette(entry, color).token(token, color);
+    }
+
+    let mut theme = builder.build();
+    theme.fill_missing();
+    theme.into_builder()
+}
+
+/// Pick seeds from a list of colors, most dominant first, and generate a
+/// theme from them. See [`ThemeBuilder::from_colors`].
+///
+/// The background and foreground keep the hue of the darkest (or lightest)
+/// color but are pushed to comfortable lightness. Accents are the most
+/// colorful entries, favoring dominant ones, with distinct hues.
+pub(crate) fn from_colors(colors: &[OpalineColor], variant: ThemeVariant) -> ThemeBuilder {
+    let dark = variant == ThemeVariant::Dark;
+    let lab: Vec<(f32, f32, f32)> = colors.iter().map(|c| c.to_oklab()).collect();
+
+    let base = if dark {
+        lab.iter().copied().min_by(|x, y| x.0.total_cmp(&y.0))
+    } else {
+        lab.iter().copied().max_by(|x, y| x.0.total_cmp(&y.0))
+    };
+    let (_, a, b) = base.unwrap_or_default();
+    let (bg, fg) = if dark {
+        (
+            OpalineColor::from_oklab(0.2, a * 0.4, b * 0.4),
+            OpalineColor::from_oklab(0.9, a * 0.15, b * 0.15),
+        )
+    } else {
+        (
+            OpalineColor::from_oklab(0.97, a * 0.15, b * 0.15),
+            OpalineColor::from_oklab(0.3, a * 0.3, b * 0.3),
+        )
+    };
+
+    // Rank by chroma, discounted by position so dominant colors win ties.
+    #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+    let mut ranked: Vec<(f32, usize)> = lab
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, a, b))| (a.hypot(b), i))
+        .filter(|&(chroma, _)| chroma >= MIN_ACCENT_CHROMA)
+        .map(|(chroma, i)| (chroma / ((i + 1) as f32).sqrt(), i))
+        .collect();
+    ranked.sort_by(|x, y| y.0.total_cmp(&x.0).then(x.1.cmp(&y.1)));
+
+    let mut accents: Vec<OpalineColor> = Vec::new();
+    for (_, i) in ranked {
+        let hue = colors[i].to_hsl().0;
+        if accents
+            .iter()
+            .all(|a| hue_distance(a.to_hsl().0, hue) >= MIN_ACCENT_HUE_DISTANCE)
+        {
+            accents.push(readable(colors[i], bg, dark));
+        }
+        if accents.len() == 3 {
+            break;
+        }
+    }
+
+    let accent = accents.first().copied().unwrap_or(FALLBACK_ACCENT);
+    from_accents(
+        bg,
+        fg,
+        accent,
+        accents.get(1..).unwrap_or_default(),
+        variant,
+    )
+}
+
+fn hue_distance(a: f32, b: f32) -> f32 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// Step `color` away from `bg` until it reaches [`MIN_CONTRAST`], or give up
+/// after it's been pushed all the way to white or black.
+fn readable(color: OpalineColor, bg: OpalineColor, dark: bool) -> OpalineColor {
+    let mut color = color;
+    for _ in 0..12 {
+        if color.contrast_ratio(bg) >= MIN_CONTRAST {
+            break;
+        }
+        color = if dark {
+            color.lighten(0.1)
+        } else {
+            color.darken(0.1)
+        };
+    }
+    color
+}
@@ -68,6 +68,55 @@ impl Gradient {
         }
     }
 
+    /// Sample the gradient at any position `t`, mapping values outside
+    /// `[0.0, 1.0]` back onto it with `wrap`.
+    ///
+    /// Feed it a time or frame counter to animate: [`GradientWrap::Repeat`]
+    /// cycles through the stops and back to the first without a seam, and
+    /// [`GradientWrap::PingPong`] runs the gradient forward, then backward.
+    #[allow(
+        clippy::cast_sign_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::as_conversions
+    )]
+    pub fn sample(&self, t: f32, wrap: GradientWrap) -> OpalineColor {
+        match wrap {
+            GradientWrap::Clamp => self.at(t),
+            GradientWrap::PingPong => {
+                let t = t.rem_euclid(2.0);
+                self.at(if t > 1.0 { 2.0 - t } else { t })
+            }
+            GradientWrap::Repeat => {
+                // A closed loop: the last stop blends back into the first.
+                let segments = self.stops.len();
+                let scaled = t.rem_euclid(1.0) * segments as f32;
+                let index = (scaled.floor() as usize).min(segments - 1);
+                let next = (index + 1) % segments;
+                self.stops[index].lerp(self.stops[next], scaled - index as f32)
+            }
+        }
+    }
+
+    /// Generate `n` colors across the gradient, shifted by `phase`.
+    ///
+    /// Like [`generate`](Self::generate), but each position is moved back
+    /// by `phase` and mapped through [`sample`](Self::sample). Increasing
+    /// `phase` moves the colors toward the end of the sequence; a phase of
+    /// `1.0` is one full cycle. With [`GradientWrap::Repeat`] the `n` colors
+    /// span one cycle of the loop, so the sequence scrolls without a seam.
+    #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+    pub fn generate_at_phase(&self, n: usize, phase: f32, wrap: GradientWrap) -> Vec<OpalineColor> {
+        let position = |i: usize| match (wrap, n) {
+            (GradientWrap::Repeat, _) => i as f32 / n as f32,
+            (_, 1) => 0.5,
+            _ => i as f32 / (n - 1) as f32,
+        };
+        (0..n)
+            .map(|i| self.sample(position(i) - phase, wrap))
+            .collect()
+    }
+
     /// Number of color stops in this gradient.
     pub fn len(&self) -> usize {
         self.stops.len()
@@ -84,6 +133,18 @@ impl Gradient {
     }
 }
 
+/// How [`Gradient::sample`] treats positions outside `[0.0, 1.0]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GradientWrap {
+    /// Hold the first or last stop, like [`Gradient::at`].
+    #[default]
+    Clamp,
+    /// Loop every `1.0`, blending the last stop back into the first.
+    Repeat,
+    /// Run forward then backward, every `2.0`.
+    PingPong,
+}
+
 impl Default for Gradient {
     fn default() -> Self {
         Self {
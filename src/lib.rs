@@ -6,7 +6,8 @@
 //! pipeline: **palette** (raw hex colors) → **tokens** (semantic names) → **styles**
 //! (composed fg/bg + modifiers). Themes can also define multi-stop **gradients**.
 //!
-//! Adapters are available for ratatui, egui, crossterm, owo-colors, syntect, and CSS.
+//! Adapters are available for ratatui, egui, crossterm, owo-colors, syntect,
+//! tree-sitter, CSS, and Tailwind.
 //!
 //! ## Quick start
 //!
@@ -31,9 +32,13 @@
 //! | `cli` | no | `colored` crate adapter for ANSI terminal output |
 //! | `global-state` | no | Process-wide `current()`/`set_theme()` singleton |
 //! | `discovery` | no | Load user themes from `~/.config/<app>/themes/` |
+//! | `image` | no | Generate themes from images (PNG, JPEG) |
 
 pub mod color;
+pub mod contract;
+mod derive;
 pub mod error;
+mod generate;
 pub mod style;
 
 #[cfg(feature = "gradients")]
@@ -53,6 +58,9 @@ pub mod builtins;
 #[cfg(feature = "discovery")]
 pub mod discovery;
 
+#[cfg(feature = "image")]
+pub mod extract;
+
 #[cfg(feature = "widgets")]
 pub mod widgets;
 
@@ -60,19 +68,27 @@ pub mod widgets;
 
 // Core types — always available
 pub use color::{ColorParseError, OpalineColor};
+pub use contract::{Contract, ContractReport};
 pub use error::OpalineError;
-pub use loader::{load_from_file, load_from_str};
+pub use loader::{
+    LoadOptions, load_from_file, load_from_file_with, load_from_str, load_from_str_with,
+};
+pub use resolver::{ColorSource, ReferenceGraph, StyleSources};
 pub use schema::{StyleDef, ThemeFile, ThemeMeta, ThemeVariant};
 pub use style::OpalineStyle;
-pub use theme::{Theme, ThemeBuilder};
+pub use theme::{Dependent, Theme, ThemeBuilder, ThemeTransition};
 
 // Gradients
 #[cfg(feature = "gradients")]
-pub use gradient::Gradient;
+pub use gradient::{Gradient, GradientWrap};
 
 // Ratatui adapter
 #[cfg(all(feature = "ratatui", feature = "gradients"))]
-pub use adapters::ratatui::{gradient_bar, gradient_line, gradient_spans, gradient_text_line};
+pub use adapters::ratatui::{
+    GradientDirection, GradientFill, fill_gradient, gradient_bar, gradient_bar_at_phase,
+    gradient_line, gradient_line_at_phase, gradient_multiline, gradient_spans,
+    gradient_spans_at_phase, gradient_text_line,
+};
 
 // CLI adapter
 #[cfg(all(feature = "cli", feature = "gradients"))]
@@ -82,7 +98,10 @@ pub use adapters::cli::{ColoredExt, ThemeCliExt};
 
 // CSS adapter
 #[cfg(feature = "css")]
-pub use adapters::css::{generate_css_classes, generate_css_vars, generate_stylesheet};
+pub use adapters::css::{
+    CssOptions, generate_css_classes, generate_css_vars, generate_stylesheet,
+    generate_stylesheet_pair,
+};
 
 // owo-colors adapter
 #[cfg(feature = "owo-colors")]
@@ -90,7 +109,7 @@ pub use adapters::owo_colors::OwoThemeExt;
 
 // Global state
 #[cfg(feature = "global-state")]
-pub use theme::{current, load_theme, set_theme};
+pub use theme::{current, load_theme, set_theme, transition_to};
 #[cfg(all(feature = "global-state", feature = "builtin-themes"))]
 pub use theme::{load_theme_by_name, load_theme_by_name_with};
 #[cfg(all(
@@ -113,5 +132,10 @@ pub use builtins::{list_available_themes_for_app, list_available_themes_in_dirs}
 pub use discovery::{app_theme_dirs, theme_dirs};
 
 // Widgets
+#[cfg(all(feature = "widgets", feature = "gradients"))]
+pub use widgets::GradientBlock;
 #[cfg(feature = "widgets")]
-pub use widgets::{ThemeSelector, ThemeSelectorAction, ThemeSelectorState};
+pub use widgets::{
+    ThemeHistory, ThemeHistoryStore, ThemeSelector, ThemeSelectorAction, ThemeSelectorState,
+    TomlHistoryStore,
+};
@@ -1,29 +1,108 @@
 use std::path::Path;
 
+use crate::contract::Contract;
 use crate::error::OpalineError;
 use crate::resolver;
 use crate::schema::ThemeFile;
 use crate::theme::Theme;
 
+/// Options for [`load_from_str_with`] and [`load_from_file_with`].
+///
+/// By default loading only checks that references resolve. Options can derive
+/// missing contract names, and a contract rejects themes that still leave
+/// required names undefined:
+///
+/// ```rust
+/// use opaline::{Contract, LoadOptions};
+///
+/// let options = LoadOptions::new()
+///     .contract(Contract::standard().require_tokens(["git.added", "git.removed"]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    contract: Option<Contract>,
+    fill_missing: bool,
+}
+
+impl LoadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require the standard contract from [`names`](crate::names).
+    #[must_use]
+    pub fn strict(self) -> Self {
+        self.contract(Contract::standard())
+    }
+
+    /// Derive missing standard-contract names with
+    /// [`Theme::fill_missing`] before any contract check.
+    #[must_use]
+    pub fn fill_missing(mut self) -> Self {
+        self.fill_missing = true;
+        self
+    }
+
+    /// Require every name in `contract`. Loading fails with
+    /// [`OpalineError::ContractViolation`] when any are missing.
+    #[must_use]
+    pub fn contract(mut self, contract: Contract) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+}
+
 /// Load a theme from a TOML string.
 ///
 /// The optional `path` is stored for error diagnostics only.
 pub fn load_from_str(toml_str: &str, path: Option<&Path>) -> Result<Theme, OpalineError> {
+    load_from_str_with(toml_str, path, &LoadOptions::default())
+}
+
+/// Load a theme from a TOML string with [`LoadOptions`].
+pub fn load_from_str_with(
+    toml_str: &str,
+    path: Option<&Path>,
+    options: &LoadOptions,
+) -> Result<Theme, OpalineError> {
     let theme_file: ThemeFile = toml::from_str(toml_str).map_err(|source| OpalineError::Parse {
         path: path.map(Path::to_path_buf),
         source,
     })?;
 
     let resolved = resolver::resolve(&theme_file)?;
-    Ok(Theme::from_resolved(theme_file.meta, resolved))
+    let mut theme = Theme::from_resolved(theme_file.meta, resolved);
+
+    if options.fill_missing {
+        theme.fill_missing();
+    }
+
+    if let Some(contract) = &options.contract {
+        let report = contract.check(&theme);
+        if !report.is_satisfied() {
+            return Err(OpalineError::ContractViolation {
+                theme: theme.meta.name,
+                report: Box::new(report),
+            });
+        }
+    }
+    Ok(theme)
 }
 
 /// Load a theme from a TOML file on disk.
 pub fn load_from_file(path: impl AsRef<Path>) -> Result<Theme, OpalineError> {
+    load_from_file_with(path, &LoadOptions::default())
+}
+
+/// Load a theme from a TOML file on disk with [`LoadOptions`].
+pub fn load_from_file_with(
+    path: impl AsRef<Path>,
+    options: &LoadOptions,
+) -> Result<Theme, OpalineError> {
     let path = path.as_ref();
     let contents = std::fs::read_to_string(path).map_err(|source| OpalineError::Io {
         path: path.to_path_buf(),
         source,
     })?;
-    load_from_str(&contents, Some(path))
+    load_from_str_with(&contents, Some(path), options)
 }
@@ -2,6 +2,8 @@
 //!
 //! Every builtin theme defines these tokens, styles, and gradients.
 //! Use these instead of raw strings for autocomplete and typo prevention.
+//! [`Contract::standard`](crate::Contract::standard) checks themes against
+//! these names.
 //!
 //! ```rust
 //! use opaline::{Theme, names::tokens, names::styles};
@@ -43,6 +45,36 @@ pub mod tokens {
     pub const CODE_COMMENT: &str = "code.comment";
     pub const CODE_TYPE: &str = "code.type";
     pub const CODE_LINE_NUMBER: &str = "code.line_number";
+
+    /// Every required token name.
+    pub const ALL: &[&str] = &[
+        TEXT_PRIMARY,
+        TEXT_SECONDARY,
+        TEXT_MUTED,
+        TEXT_DIM,
+        BG_BASE,
+        BG_PANEL,
+        BG_CODE,
+        BG_HIGHLIGHT,
+        BG_SELECTION,
+        ACCENT_PRIMARY,
+        ACCENT_SECONDARY,
+        ACCENT_TERTIARY,
+        ACCENT_DEEP,
+        SUCCESS,
+        ERROR,
+        WARNING,
+        INFO,
+        BORDER_FOCUSED,
+        BORDER_UNFOCUSED,
+        CODE_KEYWORD,
+        CODE_FUNCTION,
+        CODE_STRING,
+        CODE_NUMBER,
+        CODE_COMMENT,
+        CODE_TYPE,
+        CODE_LINE_NUMBER,
+    ];
 }
 
 /// Named style constants (13 required).
@@ -60,6 +92,23 @@ pub mod styles {
     pub const DIMMED: &str = "dimmed";
     pub const MUTED: &str = "muted";
     pub const INLINE_CODE: &str = "inline_code";
+
+    /// Every required style name.
+    pub const ALL: &[&str] = &[
+        KEYWORD,
+        LINE_NUMBER,
+        SELECTED,
+        ACTIVE_SELECTED,
+        FOCUSED_BORDER,
+        UNFOCUSED_BORDER,
+        SUCCESS_STYLE,
+        ERROR_STYLE,
+        WARNING_STYLE,
+        INFO_STYLE,
+        DIMMED,
+        MUTED,
+        INLINE_CODE,
+    ];
 }
 
 /// Named gradient constants (5 required).
@@ -69,4 +118,7 @@ pub mod gradients {
     pub const SUCCESS_GRADIENT: &str = "success_gradient";
     pub const ERROR_GRADIENT: &str = "error_gradient";
     pub const AURORA: &str = "aurora";
+
+    /// Every required gradient name.
+    pub const ALL: &[&str] = &[PRIMARY, WARM, SUCCESS_GRADIENT, ERROR_GRADIENT, AURORA];
 }
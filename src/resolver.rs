@@ -15,6 +15,78 @@ pub struct ResolvedTheme {
     pub styles: HashMap<String, OpalineStyle>,
     #[cfg(feature = "gradients")]
     pub gradients: HashMap<String, Gradient>,
+    pub syntax: HashMap<String, String>,
+    /// Where each token, style color, and gradient stop came from.
+    pub graph: ReferenceGraph,
+}
+
+/// The direct reference behind a resolved color.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColorSource {
+    /// A `#rrggbb` literal (or a color set programmatically).
+    Hex(OpalineColor),
+    /// A palette entry.
+    Palette(String),
+    /// Another token.
+    Token(String),
+}
+
+impl ColorSource {
+    /// Classify a raw reference the way the resolver looked it up.
+    fn from_ref(
+        reference: &str,
+        color: OpalineColor,
+        palette_first: bool,
+        palette: &HashMap<String, OpalineColor>,
+        tokens: &HashMap<String, OpalineColor>,
+    ) -> Self {
+        if reference.starts_with('#') {
+            return Self::Hex(color);
+        }
+        let in_palette = palette.contains_key(reference);
+        if in_palette && (palette_first || !tokens.contains_key(reference)) {
+            Self::Palette(reference.to_string())
+        } else {
+            Self::Token(reference.to_string())
+        }
+    }
+
+    /// The referenced name, or `None` for hex literals.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Hex(_) => None,
+            Self::Palette(name) | Self::Token(name) => Some(name),
+        }
+    }
+}
+
+/// Formats the source as it would be written in a theme file: a hex literal
+/// or the referenced name.
+impl std::fmt::Display for ColorSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hex(color) => f.write_str(&color.to_hex()),
+            Self::Palette(name) | Self::Token(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Foreground/background sources for a style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleSources {
+    pub fg: Option<ColorSource>,
+    pub bg: Option<ColorSource>,
+}
+
+/// Direct references recorded during resolution.
+///
+/// Palette entries are the roots of the graph and have no entry of their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReferenceGraph {
+    pub tokens: HashMap<String, ColorSource>,
+    pub styles: HashMap<String, StyleSources>,
+    #[cfg(feature = "gradients")]
+    pub gradients: HashMap<String, Vec<ColorSource>>,
 }
 
 /// Resolve a parsed `ThemeFile` into concrete colors, styles, and gradients.
@@ -24,12 +96,37 @@ pub struct ResolvedTheme {
 /// 2. **Tokens**: Recursive resolution — references palette names, other tokens, or hex
 /// 3. **Styles**: `fg`/`bg` resolved via tokens → palette → hex
 /// 4. **Gradients**: Each stop resolved via tokens → palette → hex
+/// 5. **Syntax**: Each scope target must name a token, palette entry, or style
 pub fn resolve(theme_file: &ThemeFile) -> Result<ResolvedTheme, OpalineError> {
-    let palette = resolve_palette(&theme_file.palette)?;
-    let tokens = resolve_tokens(&theme_file.tokens, &palette)?;
-    let styles = resolve_styles(&theme_file.styles, &palette, &tokens)?;
+    resolve_into(theme_file, &mut ErrorSink::fail_fast())
+}
+
+/// Check a theme file and report every problem instead of stopping at the
+/// first one.
+///
+/// Runs the same passes as [`resolve`], so it returns an empty list exactly
+/// when [`resolve`] would succeed, and the first error listed is the one
+/// [`resolve`] returns. Entries that fail are replaced with a placeholder
+/// color so one bad palette entry or token isn't reported again by
+/// everything that references it. Empty gradients are reported as
+/// [`OpalineError::InvalidGradient`] so each one names the gradient.
+pub fn validate(theme_file: &ThemeFile) -> Vec<OpalineError> {
+    let mut sink = ErrorSink::collect();
+    let _ = resolve_into(theme_file, &mut sink);
+    sink.errors
+}
+
+fn resolve_into(
+    theme_file: &ThemeFile,
+    sink: &mut ErrorSink,
+) -> Result<ResolvedTheme, OpalineError> {
+    let mut graph = ReferenceGraph::default();
+    let palette = resolve_palette(&theme_file.palette, sink)?;
+    let tokens = resolve_tokens(&theme_file.tokens, &palette, &mut graph, sink)?;
+    let styles = resolve_styles(&theme_file.styles, &palette, &tokens, &mut graph, sink)?;
     #[cfg(feature = "gradients")]
-    let gradients = resolve_gradients(&theme_file.gradients, &palette, &tokens)?;
+    let gradients = resolve_gradients(&theme_file.gradients, &palette, &tokens, &mut graph, sink)?;
+    let syntax = resolve_syntax(&theme_file.syntax, &palette, &tokens, &styles, sink)?;
 
     Ok(ResolvedTheme {
         palette,
@@ -37,20 +134,93 @@ pub fn resolve(theme_file: &ThemeFile) -> Result<ResolvedTheme, OpalineError> {
         styles,
         #[cfg(feature = "gradients")]
         gradients,
+        syntax,
+        graph,
     })
 }
 
+/// Where the resolution passes send errors.
+///
+/// A fail-fast sink hands the first error back so the pass stops with `?`.
+/// A collecting sink keeps every distinct error and lets the pass continue
+/// with a placeholder.
+struct ErrorSink {
+    fail_fast: bool,
+    errors: Vec<OpalineError>,
+}
+
+impl ErrorSink {
+    fn fail_fast() -> Self {
+        Self {
+            fail_fast: true,
+            errors: Vec::new(),
+        }
+    }
+
+    fn collect() -> Self {
+        Self {
+            fail_fast: false,
+            errors: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, error: OpalineError) -> Result<(), OpalineError> {
+        if self.fail_fast {
+            return Err(error);
+        }
+        if !self
+            .errors
+            .iter()
+            .any(|e| e.to_string() == error.to_string())
+        {
+            self.errors.push(error);
+        }
+        Ok(())
+    }
+
+    /// The color, or [`OpalineColor::FALLBACK`] after reporting the error.
+    fn color(
+        &mut self,
+        result: Result<OpalineColor, OpalineError>,
+    ) -> Result<OpalineColor, OpalineError> {
+        result.or_else(|error| self.report(error).map(|()| OpalineColor::FALLBACK))
+    }
+
+    /// Report an error that doesn't name its gradient. Collected errors are
+    /// deduplicated by message, so they get the name attached.
+    #[cfg(feature = "gradients")]
+    fn report_gradient(&mut self, name: &str, error: OpalineError) -> Result<(), OpalineError> {
+        if self.fail_fast {
+            return Err(error);
+        }
+        self.report(OpalineError::InvalidGradient {
+            name: name.to_string(),
+            source: Box::new(error),
+        })
+    }
+}
+
+fn sorted_entries<V>(map: &HashMap<String, V>) -> Vec<(&str, &V)> {
+    let mut entries: Vec<(&str, &V)> = map.iter().map(|(k, v)| (k.as_str(), v)).collect();
+    entries.sort_unstable_by_key(|&(k, _)| k);
+    entries
+}
+
 /// Pass 1: Every palette value must be a literal `#rrggbb` hex string.
 fn resolve_palette(
     raw: &HashMap<String, String>,
+    sink: &mut ErrorSink,
 ) -> Result<HashMap<String, OpalineColor>, OpalineError> {
     let mut palette = HashMap::with_capacity(raw.len());
-    for (name, hex) in raw {
-        let color = OpalineColor::from_hex(hex).map_err(|source| OpalineError::InvalidColor {
-            token: name.clone(),
-            source,
-        })?;
-        palette.insert(name.clone(), color);
+    for (name, hex) in sorted_entries(raw) {
+        let color =
+            sink.color(
+                OpalineColor::from_hex(hex).map_err(|source| OpalineError::InvalidColor {
+                    token: name.to_string(),
+                    source,
+                }),
+            )?;
+        palette.insert(name.to_string(), color);
     }
     Ok(palette)
 }
@@ -62,13 +232,23 @@ fn resolve_palette(
 fn resolve_tokens(
     raw: &HashMap<String, String>,
     palette: &HashMap<String, OpalineColor>,
+    graph: &mut ReferenceGraph,
+    sink: &mut ErrorSink,
 ) -> Result<HashMap<String, OpalineColor>, OpalineError> {
     let mut resolved: HashMap<String, OpalineColor> = HashMap::with_capacity(raw.len());
 
-    for name in raw.keys() {
+    for (name, _) in sorted_entries(raw) {
         if !resolved.contains_key(name) {
             let mut chain = Vec::new();
-            resolve_token(name, raw, palette, &mut resolved, &mut chain)?;
+            let color = sink.color(resolve_token(name, raw, palette, &mut resolved, &mut chain))?;
+            resolved.insert(name.to_string(), color);
+        }
+    }
+
+    for (name, value) in raw {
+        if let Some(&color) = resolved.get(name) {
+            let source = ColorSource::from_ref(value, color, true, palette, &resolved);
+            graph.tokens.insert(name.clone(), source);
         }
     }
 
@@ -142,51 +322,70 @@ fn resolve_named_color_ref(
         .or_else(|| palette.get(reference).copied())
 }
 
+/// Resolve a style channel or gradient stop owned by `owner`.
+fn resolve_color_ref(
+    owner: String,
+    reference: &str,
+    palette: &HashMap<String, OpalineColor>,
+    tokens: &HashMap<String, OpalineColor>,
+) -> Result<OpalineColor, OpalineError> {
+    if reference.starts_with('#') {
+        OpalineColor::from_hex(reference).map_err(|source| OpalineError::InvalidColor {
+            token: owner,
+            source,
+        })
+    } else {
+        resolve_named_color_ref(reference, palette, tokens).ok_or_else(|| {
+            OpalineError::UnresolvedToken {
+                token: owner,
+                reference: reference.to_string(),
+            }
+        })
+    }
+}
+
 /// Pass 3: Resolve style definitions into concrete `OpalineStyle` values.
 fn resolve_styles(
     raw: &HashMap<String, StyleDef>,
     palette: &HashMap<String, OpalineColor>,
     tokens: &HashMap<String, OpalineColor>,
+    graph: &mut ReferenceGraph,
+    sink: &mut ErrorSink,
 ) -> Result<HashMap<String, OpalineStyle>, OpalineError> {
     let mut styles = HashMap::with_capacity(raw.len());
 
-    for (name, def) in raw {
-        let fg = def.fg.as_ref().map(|r| {
-            if r.starts_with('#') {
-                OpalineColor::from_hex(r).map_err(|source| OpalineError::InvalidColor {
-                    token: format!("{name}.fg"),
-                    source,
-                })
-            } else {
-                resolve_named_color_ref(r, palette, tokens).ok_or_else(|| {
-                    OpalineError::UnresolvedToken {
-                        token: format!("{name}.fg"),
-                        reference: r.clone(),
-                    }
+    for (name, def) in sorted_entries(raw) {
+        let mut channel = |channel: &str, reference: &Option<String>| {
+            reference
+                .as_deref()
+                .map(|r| {
+                    sink.color(resolve_color_ref(
+                        format!("{name}.{channel}"),
+                        r,
+                        palette,
+                        tokens,
+                    ))
                 })
-            }
-        });
-        let fg = fg.transpose()?;
+                .transpose()
+        };
+        let fg = channel("fg", &def.fg)?;
+        let bg = channel("bg", &def.bg)?;
 
-        let bg = def.bg.as_ref().map(|r| {
-            if r.starts_with('#') {
-                OpalineColor::from_hex(r).map_err(|source| OpalineError::InvalidColor {
-                    token: format!("{name}.bg"),
-                    source,
-                })
-            } else {
-                resolve_named_color_ref(r, palette, tokens).ok_or_else(|| {
-                    OpalineError::UnresolvedToken {
-                        token: format!("{name}.bg"),
-                        reference: r.clone(),
-                    }
-                })
-            }
-        });
-        let bg = bg.transpose()?;
+        let source = |r: &Option<String>, c: Option<OpalineColor>| {
+            r.as_deref()
+                .zip(c)
+                .map(|(r, c)| ColorSource::from_ref(r, c, false, palette, tokens))
+        };
+        graph.styles.insert(
+            name.to_string(),
+            StyleSources {
+                fg: source(&def.fg, fg),
+                bg: source(&def.bg, bg),
+            },
+        );
 
         styles.insert(
-            name.clone(),
+            name.to_string(),
             OpalineStyle {
                 fg,
                 bg,
@@ -212,34 +411,54 @@ fn resolve_gradients(
     raw: &HashMap<String, Vec<String>>,
     palette: &HashMap<String, OpalineColor>,
     tokens: &HashMap<String, OpalineColor>,
+    graph: &mut ReferenceGraph,
+    sink: &mut ErrorSink,
 ) -> Result<HashMap<String, Gradient>, OpalineError> {
     let mut gradients = HashMap::with_capacity(raw.len());
 
-    for (name, stops) in raw {
+    for (name, stops) in sorted_entries(raw) {
         if stops.is_empty() {
-            return Err(OpalineError::EmptyGradient);
+            sink.report_gradient(name, OpalineError::EmptyGradient)?;
+            continue;
         }
 
         let mut colors = Vec::with_capacity(stops.len());
         for (i, stop) in stops.iter().enumerate() {
-            let color = if stop.starts_with('#') {
-                OpalineColor::from_hex(stop).map_err(|source| OpalineError::InvalidColor {
-                    token: format!("{name}[{i}]"),
-                    source,
-                })?
-            } else {
-                resolve_named_color_ref(stop, palette, tokens).ok_or_else(|| {
-                    OpalineError::UnresolvedToken {
-                        token: format!("{name}[{i}]"),
-                        reference: stop.clone(),
-                    }
-                })?
-            };
-            colors.push(color);
+            let owner = format!("{name}[{i}]");
+            colors.push(sink.color(resolve_color_ref(owner, stop, palette, tokens))?);
         }
 
-        gradients.insert(name.clone(), Gradient::new(colors));
+        let sources = stops
+            .iter()
+            .zip(&colors)
+            .map(|(stop, &color)| ColorSource::from_ref(stop, color, false, palette, tokens))
+            .collect();
+        graph.gradients.insert(name.to_string(), sources);
+        gradients.insert(name.to_string(), Gradient::new(colors));
     }
 
     Ok(gradients)
 }
+
+/// Pass 5: Validate syntax scope targets. Targets stay as names so adapters
+/// can pick up both the color and any style modifiers.
+fn resolve_syntax(
+    raw: &HashMap<String, String>,
+    palette: &HashMap<String, OpalineColor>,
+    tokens: &HashMap<String, OpalineColor>,
+    styles: &HashMap<String, OpalineStyle>,
+    sink: &mut ErrorSink,
+) -> Result<HashMap<String, String>, OpalineError> {
+    for (scope, target) in sorted_entries(raw) {
+        let known = resolve_named_color_ref(target, palette, tokens).is_some()
+            || styles.contains_key(target.as_str());
+        if !known {
+            sink.report(OpalineError::UnresolvedToken {
+                token: format!("syntax.{scope}"),
+                reference: target.clone(),
+            })?;
+        }
+    }
+
+    Ok(raw.clone())
+}
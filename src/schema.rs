@@ -3,6 +3,9 @@ use std::fmt;
 
 use serde::Deserialize;
 
+use crate::color::OpalineColor;
+use crate::style::OpalineStyle;
+
 /// Top-level structure of a `.toml` theme file.
 #[derive(Debug, Clone, Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
@@ -24,6 +27,11 @@ pub struct ThemeFile {
     /// Named gradients as arrays of color references.
     #[serde(default)]
     pub gradients: HashMap<String, Vec<String>>,
+
+    /// Syntax highlighting overrides mapping `TextMate` scope selectors to
+    /// token, palette, or style names (e.g. `"keyword.control" = "accent.secondary"`).
+    #[serde(default)]
+    pub syntax: HashMap<String, String>,
 }
 
 /// Theme metadata from the `[meta]` section.
@@ -105,3 +113,22 @@ pub struct StyleDef {
     #[serde(default)]
     pub crossed_out: bool,
 }
+
+impl From<&OpalineStyle> for StyleDef {
+    /// Literal hex colors and the style's modifiers.
+    fn from(style: &OpalineStyle) -> Self {
+        Self {
+            fg: style.fg.map(OpalineColor::to_hex),
+            bg: style.bg.map(OpalineColor::to_hex),
+            bold: style.bold,
+            dim: style.dim,
+            italic: style.italic,
+            underline: style.underline,
+            slow_blink: style.slow_blink,
+            rapid_blink: style.rapid_blink,
+            reversed: style.reversed,
+            hidden: style.hidden,
+            crossed_out: style.crossed_out,
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
 use crate::color::OpalineColor;
+use crate::contract::{Contract, ContractReport};
 #[cfg(feature = "gradients")]
 use crate::gradient::Gradient;
-use crate::resolver::ResolvedTheme;
-use crate::schema::{ThemeMeta, ThemeVariant};
+use crate::resolver::{ColorSource, ReferenceGraph, ResolvedTheme, StyleSources};
+use crate::schema::{StyleDef, ThemeFile, ThemeMeta, ThemeVariant};
 use crate::style::OpalineStyle;
 
 /// A fully resolved theme ready for use.
@@ -21,6 +22,17 @@ pub struct Theme {
     styles: HashMap<String, OpalineStyle>,
     #[cfg(feature = "gradients")]
     gradients: HashMap<String, Gradient>,
+    syntax: HashMap<String, String>,
+    graph: ReferenceGraph,
+}
+
+/// Something in a theme that uses a color, as reported by
+/// [`Theme::dependents`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dependent {
+    Token(String),
+    Style(String),
+    Gradient(String),
 }
 
 impl Theme {
@@ -37,6 +49,8 @@ impl Theme {
             styles: resolved.styles,
             #[cfg(feature = "gradients")]
             gradients: resolved.gradients,
+            syntax: resolved.syntax,
+            graph: resolved.graph,
         }
     }
 
@@ -76,6 +90,11 @@ impl Theme {
         self.tokens.keys().map(String::as_str).collect()
     }
 
+    /// Look up a raw palette color, bypassing tokens.
+    pub fn palette_color(&self, name: &str) -> Option<OpalineColor> {
+        self.palette.get(name).copied()
+    }
+
     /// All palette color names defined in this theme.
     pub fn palette_names(&self) -> Vec<&str> {
         self.palette.keys().map(String::as_str).collect()
@@ -138,6 +157,215 @@ impl Theme {
         self.gradients.keys().map(String::as_str).collect()
     }
 
+    // ── Syntax access ────────────────────────────────────────────────────
+
+    /// Scope selector overrides from the theme's `[syntax]` table.
+    ///
+    /// Keys are `TextMate` scope selectors, values are token, palette, or
+    /// style names. Syntax adapters merge these over their default mappings.
+    pub fn syntax_mapping(&self) -> &HashMap<String, String> {
+        &self.syntax
+    }
+
+    // ── Provenance ───────────────────────────────────────────────────────
+
+    /// The reference graph recorded when the theme was resolved.
+    ///
+    /// Only references that came from a theme file appear here; colors set
+    /// programmatically are reported as [`ColorSource::Hex`] by the accessors
+    /// below.
+    pub fn graph(&self) -> &ReferenceGraph {
+        &self.graph
+    }
+
+    /// What a token refers to directly, or `None` if the token doesn't exist.
+    pub fn token_reference(&self, name: &str) -> Option<ColorSource> {
+        self.graph
+            .tokens
+            .get(name)
+            .cloned()
+            .or_else(|| self.tokens.get(name).map(|&c| ColorSource::Hex(c)))
+    }
+
+    /// The full reference chain behind a token, nearest reference first.
+    ///
+    /// For `"border.focused" = "accent.primary"` and
+    /// `"accent.primary" = "purple_500"` this returns
+    /// `[Token("accent.primary"), Palette("purple_500")]`. The last entry is
+    /// always a palette entry or a hex literal.
+    pub fn token_source(&self, name: &str) -> Option<Vec<ColorSource>> {
+        let mut chain = vec![self.token_reference(name)?];
+        while let Some(ColorSource::Token(next)) = chain.last() {
+            let Some(source) = self.token_reference(next) else {
+                break;
+            };
+            // Resolution rejects cycles, but a registered token can still
+            // shadow one; stop rather than loop.
+            if chain.contains(&source) {
+                break;
+            }
+            chain.push(source);
+        }
+        Some(chain)
+    }
+
+    /// Where a style's foreground and background came from.
+    pub fn style_sources(&self, name: &str) -> Option<StyleSources> {
+        if let Some(sources) = self.graph.styles.get(name) {
+            return Some(sources.clone());
+        }
+        self.styles.get(name).map(|style| StyleSources {
+            fg: style.fg.map(ColorSource::Hex),
+            bg: style.bg.map(ColorSource::Hex),
+        })
+    }
+
+    /// Where each stop of a gradient came from.
+    #[cfg(feature = "gradients")]
+    pub fn gradient_sources(&self, name: &str) -> Option<Vec<ColorSource>> {
+        if let Some(sources) = self.graph.gradients.get(name) {
+            return Some(sources.clone());
+        }
+        self.gradients
+            .get(name)
+            .map(|g| g.stops().iter().map(|&c| ColorSource::Hex(c)).collect())
+    }
+
+    /// Every token, style, and gradient that uses a palette entry or token,
+    /// directly or through other tokens. Sorted, tokens first.
+    pub fn dependents(&self, name: &str) -> Vec<Dependent> {
+        let mut found: Vec<Dependent> = Vec::new();
+        let uses = |source: &ColorSource| self.source_uses(source, name);
+
+        for (token, source) in &self.graph.tokens {
+            if uses(source) {
+                found.push(Dependent::Token(token.clone()));
+            }
+        }
+        for (style, sources) in &self.graph.styles {
+            if sources.fg.iter().chain(&sources.bg).any(uses) {
+                found.push(Dependent::Style(style.clone()));
+            }
+        }
+        #[cfg(feature = "gradients")]
+        for (gradient, sources) in &self.graph.gradients {
+            if sources.iter().any(uses) {
+                found.push(Dependent::Gradient(gradient.clone()));
+            }
+        }
+
+        found.sort();
+        found
+    }
+
+    /// Whether `source` is `name` or reaches it through token references.
+    fn source_uses(&self, source: &ColorSource, name: &str) -> bool {
+        match source {
+            ColorSource::Hex(_) => false,
+            ColorSource::Palette(p) => p == name,
+            ColorSource::Token(t) => {
+                t == name
+                    || self
+                        .token_source(t)
+                        .is_some_and(|chain| chain.iter().any(|s| s.name() == Some(name)))
+            }
+        }
+    }
+
+    /// Rebuild a [`ThemeFile`] from this theme.
+    ///
+    /// Palette entries are written as hex. Tokens, style colors, and gradient
+    /// stops are written as the references recorded during resolution, so a
+    /// loaded theme round-trips to equivalent TOML. Colors set with
+    /// [`ThemeBuilder`] or `register_*` have no reference and are written as
+    /// literal hex values.
+    pub fn to_theme_file(&self) -> ThemeFile {
+        let palette = self
+            .palette
+            .iter()
+            .map(|(name, color)| (name.clone(), color.to_hex()))
+            .collect();
+        let tokens = self
+            .token_names()
+            .into_iter()
+            .filter_map(|name| Some((name.to_string(), self.token_reference(name)?.to_string())))
+            .collect();
+        let styles = self
+            .styles
+            .iter()
+            .map(|(name, style)| {
+                let mut def = StyleDef::from(style);
+                if let Some(sources) = self.graph.styles.get(name) {
+                    def.fg = sources.fg.as_ref().map(ToString::to_string).or(def.fg);
+                    def.bg = sources.bg.as_ref().map(ToString::to_string).or(def.bg);
+                }
+                (name.clone(), def)
+            })
+            .collect();
+
+        #[cfg(feature = "gradients")]
+        let gradients = self
+            .gradient_names()
+            .into_iter()
+            .filter_map(|name| {
+                let stops = self.gradient_sources(name)?;
+                Some((
+                    name.to_string(),
+                    stops.iter().map(ToString::to_string).collect(),
+                ))
+            })
+            .collect();
+        #[cfg(not(feature = "gradients"))]
+        let gradients = HashMap::new();
+
+        ThemeFile {
+            meta: self.meta.clone(),
+            palette,
+            tokens,
+            styles,
+            gradients,
+            syntax: self.syntax.clone(),
+        }
+    }
+
+    /// Check this theme against the standard contract in
+    /// [`names`](crate::names), reporting missing and extra names.
+    ///
+    /// Use [`Contract::check`] to include app-specific requirements.
+    pub fn validate_contract(&self) -> ContractReport {
+        Contract::standard().check(self)
+    }
+
+    /// Derive every missing standard-contract token, style, and gradient
+    /// from the ones the theme does define.
+    ///
+    /// Rules are variant-aware: `bg.selection` is `bg.base` lightened on dark
+    /// themes and darkened on light ones, `text.muted` fades `text.primary`
+    /// toward `bg.base`, and `warm` runs `warning → error`. Names the theme
+    /// defines are never changed. When a root such as `error` can't be
+    /// derived at all, a conventional color for the variant is used, so the
+    /// theme always satisfies the standard contract afterwards.
+    ///
+    /// Returns the contract report from before filling, listing what was
+    /// derived.
+    pub fn fill_missing(&mut self) -> ContractReport {
+        crate::derive::fill_missing(self)
+    }
+
+    /// Turn a theme back into a builder with the same colors, dropping
+    /// provenance.
+    pub(crate) fn into_builder(self) -> ThemeBuilder {
+        ThemeBuilder {
+            meta: self.meta,
+            palette: self.palette,
+            tokens: self.tokens,
+            styles: self.styles,
+            #[cfg(feature = "gradients")]
+            gradients: self.gradients,
+            syntax: self.syntax,
+        }
+    }
+
     // ── Variant helpers ──────────────────────────────────────────────────
 
     /// Whether this is a dark theme.
@@ -164,8 +392,13 @@ impl Theme {
     }
 
     /// Register a token, overwriting any existing value.
+    ///
+    /// The token's recorded provenance is dropped; it now reports as a
+    /// [`ColorSource::Hex`].
     pub fn register_token(&mut self, name: impl Into<String>, color: OpalineColor) {
-        self.tokens.insert(name.into(), color);
+        let key = name.into();
+        self.graph.tokens.remove(&key);
+        self.tokens.insert(key, color);
     }
 
     /// Register a style only if the theme doesn't already define it.
@@ -176,7 +409,111 @@ impl Theme {
 
     /// Register a style, overwriting any existing value.
     pub fn register_style(&mut self, name: impl Into<String>, style: OpalineStyle) {
-        self.styles.insert(name.into(), style);
+        let key = name.into();
+        self.graph.styles.remove(&key);
+        self.styles.insert(key, style);
+    }
+
+    /// Register a gradient only if the theme doesn't already define it.
+    #[cfg(feature = "gradients")]
+    pub fn register_default_gradient(&mut self, name: impl Into<String>, gradient: Gradient) {
+        let key = name.into();
+        self.gradients.entry(key).or_insert(gradient);
+    }
+
+    /// Register a gradient, overwriting any existing value.
+    #[cfg(feature = "gradients")]
+    pub fn register_gradient(&mut self, name: impl Into<String>, gradient: Gradient) {
+        let key = name.into();
+        self.graph.gradients.remove(&key);
+        self.gradients.insert(key, gradient);
+    }
+
+    // ── Interpolation ─────────────────────────────────────────────────
+
+    /// Blend two themes, for crossfading from `a` (`t = 0.0`) to `b`
+    /// (`t = 1.0`).
+    ///
+    /// Palette entries, tokens, style colors, and gradient stops defined by
+    /// both themes are lerped. Gradients with different stop counts are
+    /// resampled to the longer one first. Everything else — names only one
+    /// theme defines, style modifiers, syntax mappings, and metadata — comes
+    /// from whichever theme `t` is closer to. Provenance is dropped in
+    /// between the endpoints.
+    ///
+    /// See [`ThemeTransition`] to step through a crossfade frame by frame.
+    #[must_use]
+    pub fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        if t <= 0.0 {
+            return a.clone();
+        }
+        if t >= 1.0 {
+            return b.clone();
+        }
+        let (near, far) = if t < 0.5 { (a, b) } else { (b, a) };
+        // Lerp from `a` to `b`, whichever side the entry was looked up on.
+        let mix = |near_color: OpalineColor, far_color: OpalineColor| {
+            if t < 0.5 {
+                near_color.lerp(far_color, t)
+            } else {
+                far_color.lerp(near_color, t)
+            }
+        };
+        let blend = |near: &HashMap<String, OpalineColor>, far: &HashMap<String, OpalineColor>| {
+            near.iter()
+                .map(|(name, &color)| {
+                    let color = far.get(name).map_or(color, |&other| mix(color, other));
+                    (name.clone(), color)
+                })
+                .collect()
+        };
+        let blend_color = |near: Option<OpalineColor>, far: Option<OpalineColor>| match (near, far)
+        {
+            (Some(near), Some(far)) => Some(mix(near, far)),
+            (near, _) => near,
+        };
+
+        Self {
+            meta: near.meta.clone(),
+            palette: blend(&near.palette, &far.palette),
+            tokens: blend(&near.tokens, &far.tokens),
+            styles: near
+                .styles
+                .iter()
+                .map(|(name, style)| {
+                    let mut style = style.clone();
+                    if let Some(other) = far.styles.get(name) {
+                        style.fg = blend_color(style.fg, other.fg);
+                        style.bg = blend_color(style.bg, other.bg);
+                    }
+                    (name.clone(), style)
+                })
+                .collect(),
+            #[cfg(feature = "gradients")]
+            gradients: near
+                .gradients
+                .iter()
+                .map(|(name, gradient)| {
+                    let gradient = far.gradients.get(name).map_or_else(
+                        || gradient.clone(),
+                        |other| {
+                            let n = gradient.len().max(other.len());
+                            let stops = gradient
+                                .generate(n)
+                                .into_iter()
+                                .zip(other.generate(n))
+                                .map(|(near, far)| mix(near, far))
+                                .collect();
+                            Gradient::new(stops)
+                        },
+                    );
+                    (name.clone(), gradient)
+                })
+                .collect(),
+            syntax: near.syntax.clone(),
+            graph: ReferenceGraph::default(),
+        }
     }
 }
 
@@ -202,6 +539,7 @@ pub struct ThemeBuilder {
     styles: HashMap<String, OpalineStyle>,
     #[cfg(feature = "gradients")]
     gradients: HashMap<String, Gradient>,
+    syntax: HashMap<String, String>,
 }
 
 impl ThemeBuilder {
@@ -213,9 +551,86 @@ impl ThemeBuilder {
             styles: HashMap::new(),
             #[cfg(feature = "gradients")]
             gradients: HashMap::new(),
+            syntax: HashMap::new(),
         }
     }
 
+    /// Generate a complete theme from a background, foreground, and accent.
+    ///
+    /// Secondary and tertiary accents are spread around the hue wheel from
+    /// `accent`; success, error, warning, and info keep their conventional
+    /// hues at the accent's saturation. Generated colors are adjusted to at
+    /// least 4.5:1 contrast against `bg`. Everything else in the standard
+    /// contract is derived like [`Theme::fill_missing`], so the result
+    /// satisfies it. Output depends only on the inputs.
+    ///
+    /// The builder is named `"Generated"`; rename it with
+    /// [`name`](Self::name). Later builder calls override generated entries,
+    /// but styles and gradients keep the colors they were generated with.
+    ///
+    /// ```rust
+    /// use opaline::{OpalineColor, ThemeBuilder, ThemeVariant};
+    ///
+    /// let theme = ThemeBuilder::from_seeds(
+    ///     OpalineColor::new(0x1a, 0x1b, 0x26),
+    ///     OpalineColor::new(0xc0, 0xca, 0xf5),
+    ///     OpalineColor::new(0x7a, 0xa2, 0xf7),
+    ///     ThemeVariant::Dark,
+    /// )
+    /// .name("Night City")
+    /// .build();
+    ///
+    /// assert!(theme.validate_contract().is_satisfied());
+    /// ```
+    pub fn from_seeds(
+        bg: OpalineColor,
+        fg: OpalineColor,
+        accent: OpalineColor,
+        variant: ThemeVariant,
+    ) -> Self {
+        crate::generate::from_seeds(bg, fg, accent, variant)
+    }
+
+    /// Generate a contract-complete theme from a list of colors, most
+    /// dominant first — typically the output of
+    /// [`extract::dominant_colors`](crate::extract::dominant_colors).
+    ///
+    /// The darkest color (lightest for light themes) sets the background tint,
+    /// and the most colorful ones with distinct hues become the accents. The
+    /// rest follows [`from_seeds`](Self::from_seeds).
+    pub fn from_colors(colors: &[OpalineColor], variant: ThemeVariant) -> Self {
+        crate::generate::from_colors(colors, variant)
+    }
+
+    /// Generate a theme that matches an image, such as a wallpaper. The theme
+    /// is named after the file stem.
+    ///
+    /// ```rust,no_run
+    /// use opaline::{ThemeBuilder, ThemeVariant};
+    ///
+    /// let theme = ThemeBuilder::from_image("wallpaper.jpg", ThemeVariant::Dark)?.build();
+    /// # Ok::<(), opaline::OpalineError>(())
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn from_image(
+        path: impl AsRef<std::path::Path>,
+        variant: ThemeVariant,
+    ) -> Result<Self, crate::error::OpalineError> {
+        let path = path.as_ref();
+        let colors = crate::extract::palette_from_image(path, crate::extract::DEFAULT_COLORS)?;
+        let name = path
+            .file_stem()
+            .map_or_else(|| "Image".to_string(), |s| s.to_string_lossy().into_owned());
+        Ok(Self::from_colors(&colors, variant).name(name))
+    }
+
+    /// Set the theme name.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.meta.name = name.into();
+        self
+    }
+
     /// Set the theme author.
     #[must_use]
     pub fn author(mut self, author: impl Into<String>) -> Self {
@@ -273,6 +688,13 @@ impl ThemeBuilder {
         self
     }
 
+    /// Map a syntax scope selector to a token, palette, or style name.
+    #[must_use]
+    pub fn syntax(mut self, scope: impl Into<String>, target: impl Into<String>) -> Self {
+        self.syntax.insert(scope.into(), target.into());
+        self
+    }
+
     /// Build the theme.
     #[must_use]
     pub fn build(self) -> Theme {
@@ -283,6 +705,8 @@ impl ThemeBuilder {
             styles: self.styles,
             #[cfg(feature = "gradients")]
             gradients: self.gradients,
+            syntax: self.syntax,
+            graph: ReferenceGraph::default(),
         }
     }
 }
@@ -310,11 +734,84 @@ impl Default for Theme {
                 styles: HashMap::new(),
                 #[cfg(feature = "gradients")]
                 gradients: HashMap::new(),
+                syntax: HashMap::new(),
+                graph: ReferenceGraph::default(),
             }
         }
     }
 }
 
+// ── Transitions ─────────────────────────────────────────────────────
+
+/// A crossfade between two themes over a fixed number of frames.
+///
+/// Call [`advance`](Self::advance) once per frame to get the next blended
+/// theme from [`Theme::interpolate`]. With the `global-state` feature,
+/// [`transition_to`] starts from the active theme and
+/// [`tick`](Self::tick) installs each frame with `set_theme`:
+///
+/// ```rust,ignore
+/// let mut transition = Some(opaline::transition_to(next_theme, 12));
+///
+/// loop {
+///     if transition.as_mut().is_some_and(|t| !t.tick()) {
+///         transition = None;
+///     }
+///     terminal.draw(|frame| draw(frame, &opaline::current()))?;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeTransition {
+    from: Theme,
+    to: Theme,
+    frame: u32,
+    frames: u32,
+}
+
+impl ThemeTransition {
+    /// Fade from `from` to `to` over `frames` frames (at least one).
+    pub fn new(from: Theme, to: Theme, frames: u32) -> Self {
+        Self {
+            from,
+            to,
+            frame: 0,
+            frames: frames.max(1),
+        }
+    }
+
+    /// How far along the fade is, from `0.0` to `1.0`.
+    #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+    pub fn progress(&self) -> f32 {
+        self.frame as f32 / self.frames as f32
+    }
+
+    /// Whether the last frame has been produced.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.frames
+    }
+
+    /// The blended theme for the current frame.
+    pub fn theme(&self) -> Theme {
+        Theme::interpolate(&self.from, &self.to, self.progress())
+    }
+
+    /// Step to the next frame and return its theme. Once finished, keeps
+    /// returning the target theme.
+    pub fn advance(&mut self) -> Theme {
+        self.frame = self.frame.saturating_add(1).min(self.frames);
+        self.theme()
+    }
+
+    /// Step to the next frame and make it the active global theme. Returns
+    /// `true` while frames remain, so the frame that lands on the target
+    /// returns `false`.
+    #[cfg(feature = "global-state")]
+    pub fn tick(&mut self) -> bool {
+        set_theme(self.advance());
+        !self.is_finished()
+    }
+}
+
 // ── Global state (behind `global-state` feature) ─────────────────────────
 
 #[cfg(feature = "global-state")]
@@ -359,6 +856,12 @@ mod global {
         *ACTIVE_THEME.write() = Arc::new(theme);
     }
 
+    /// Start a [`ThemeTransition`] from the active global theme to `theme`.
+    /// Call [`ThemeTransition::tick`] once per frame to play it.
+    pub fn transition_to(theme: Theme, frames: u32) -> super::ThemeTransition {
+        super::ThemeTransition::new(Theme::clone(&current()), theme, frames)
+    }
+
     /// Load a theme by name and set it as the active global theme.
     ///
     /// Searches discovery paths first, then builtins. File-backed themes win
@@ -480,7 +983,7 @@ mod global {
 }
 
 #[cfg(feature = "global-state")]
-pub use global::{current, load_theme, set_theme};
+pub use global::{current, load_theme, set_theme, transition_to};
 
 #[cfg(all(feature = "global-state", feature = "builtin-themes"))]
 pub use global::load_theme_by_name;
@@ -0,0 +1,152 @@
+//! A `Block` whose border runs along a theme gradient.
+//!
+//! The border is painted clockwise from the top-left corner, so the gradient
+//! flows around the perimeter instead of restarting on every side. Titles sit
+//! on the border rows and pick up the same colors.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! let block = GradientBlock::new(&theme)
+//!     .block(Block::new().title(" Files ").border_type(BorderType::Rounded))
+//!     .gradient("aurora")
+//!     .focused(pane == Pane::Files);
+//!
+//! let inner = block.inner(area);
+//! frame.render_widget(block, area);
+//! frame.render_widget(file_list, inner);
+//! ```
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Borders, Widget};
+
+use crate::names::{gradients, styles};
+use crate::theme::Theme;
+
+/// A [`Block`] with a gradient border.
+///
+/// Focused blocks use the [`focused_border`](styles::FOCUSED_BORDER) style
+/// with the gradient painted over its foreground. Unfocused blocks use the
+/// flat [`unfocused_border`](styles::UNFOCUSED_BORDER) style, so focus stands
+/// out. A theme without the gradient falls back to the flat style too.
+#[derive(Debug, Clone)]
+pub struct GradientBlock<'a> {
+    theme: &'a Theme,
+    block: Block<'a>,
+    borders: Borders,
+    gradient: &'a str,
+    focused: bool,
+}
+
+impl<'a> GradientBlock<'a> {
+    /// A focused, fully bordered block on the `primary` gradient.
+    pub fn new(theme: &'a Theme) -> Self {
+        Self {
+            theme,
+            block: Block::new(),
+            borders: Borders::ALL,
+            gradient: gradients::PRIMARY,
+            focused: true,
+        }
+    }
+
+    /// Wrap an existing block for its titles, border type, padding, and
+    /// style. Its borders and border style are replaced.
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Which sides to draw. Defaults to [`Borders::ALL`].
+    #[must_use]
+    pub const fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// The theme gradient to paint the border with.
+    #[must_use]
+    pub const fn gradient(mut self, name: &'a str) -> Self {
+        self.gradient = name;
+        self
+    }
+
+    /// Whether the block has focus. Defaults to `true`.
+    #[must_use]
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// The area inside the borders and padding.
+    pub fn inner(&self, area: Rect) -> Rect {
+        self.block.clone().borders(self.borders).inner(area)
+    }
+}
+
+impl Widget for GradientBlock<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &GradientBlock<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let style_name = if self.focused {
+            styles::FOCUSED_BORDER
+        } else {
+            styles::UNFOCUSED_BORDER
+        };
+        self.block
+            .clone()
+            .borders(self.borders)
+            .border_style(Style::from(self.theme.style(style_name)))
+            .render(area, buf);
+
+        if !self.focused || area.is_empty() {
+            return;
+        }
+        let Some(gradient) = self.theme.get_gradient(self.gradient) else {
+            return;
+        };
+
+        let perimeter = perimeter(area);
+        #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+        let steps = perimeter.len().saturating_sub(1).max(1) as f32;
+        for (i, position) in perimeter.into_iter().enumerate() {
+            if !on_border(area, self.borders, position) {
+                continue;
+            }
+            #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+            let color = Color::from(gradient.at(i as f32 / steps));
+            if let Some(cell) = buf.cell_mut(position) {
+                cell.set_fg(color);
+            }
+        }
+    }
+}
+
+/// Every cell on the edge of `area`, clockwise from the top-left corner.
+fn perimeter(area: Rect) -> Vec<Position> {
+    let (left, top) = (area.left(), area.top());
+    let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+
+    let mut cells: Vec<Position> = (left..=right).map(|x| Position::new(x, top)).collect();
+    cells.extend((top + 1..=bottom).map(|y| Position::new(right, y)));
+    if bottom > top && right > left {
+        cells.extend((left..right).rev().map(|x| Position::new(x, bottom)));
+        cells.extend((top + 1..bottom).rev().map(|y| Position::new(left, y)));
+    }
+    cells
+}
+
+fn on_border(area: Rect, borders: Borders, position: Position) -> bool {
+    (borders.contains(Borders::TOP) && position.y == area.top())
+        || (borders.contains(Borders::BOTTOM) && position.y == area.bottom() - 1)
+        || (borders.contains(Borders::LEFT) && position.x == area.left())
+        || (borders.contains(Borders::RIGHT) && position.x == area.right() - 1)
+}
@@ -2,6 +2,12 @@
 //!
 //! Requires the `widgets` feature flag.
 
+#[cfg(feature = "gradients")]
+mod gradient_block;
+mod theme_history;
 mod theme_selector;
 
+#[cfg(feature = "gradients")]
+pub use gradient_block::GradientBlock;
+pub use theme_history::{MAX_RECENT, ThemeHistory, ThemeHistoryStore, TomlHistoryStore};
 pub use theme_selector::{ThemeSelector, ThemeSelectorAction, ThemeSelectorState};
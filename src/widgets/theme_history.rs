@@ -0,0 +1,135 @@
+//! Favorite and recently used themes for the theme selector.
+//!
+//! [`ThemeHistory`] is the data; a [`ThemeHistoryStore`] loads and saves it.
+//! [`TomlHistoryStore`] keeps it in a small TOML file, by default
+//! `~/.config/opaline/theme-history.toml`:
+//!
+//! ```toml
+//! favorites = ["dracula", "catppuccin-latte"]
+//! recent = ["tokyo-night", "dracula", "nord"]
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::error::OpalineError;
+
+/// How many recently used themes to remember.
+pub const MAX_RECENT: usize = 5;
+
+/// Favorite and recently used theme ids, most recent first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ThemeHistory {
+    /// Pinned theme ids, in the order they were added.
+    pub favorites: Vec<String>,
+    /// Recently selected theme ids, newest first, at most [`MAX_RECENT`].
+    pub recent: Vec<String>,
+}
+
+impl ThemeHistory {
+    /// Whether `id` is a favorite.
+    pub fn is_favorite(&self, id: &str) -> bool {
+        self.favorites.iter().any(|f| f == id)
+    }
+
+    /// Add or remove `id` from the favorites. Returns whether it's now a
+    /// favorite.
+    pub fn toggle_favorite(&mut self, id: &str) -> bool {
+        if let Some(pos) = self.favorites.iter().position(|f| f == id) {
+            self.favorites.remove(pos);
+            false
+        } else {
+            self.favorites.push(id.to_string());
+            true
+        }
+    }
+
+    /// Move `id` to the front of the recent list.
+    pub fn record_use(&mut self, id: &str) {
+        self.recent.retain(|r| r != id);
+        self.recent.insert(0, id.to_string());
+        self.recent.truncate(MAX_RECENT);
+    }
+}
+
+/// Loads and saves a [`ThemeHistory`]. Implement this to keep favorites in
+/// your app's own config instead of [`TomlHistoryStore`].
+pub trait ThemeHistoryStore {
+    /// Read the saved history. A store with nothing saved yet should return
+    /// an empty history, not an error.
+    fn load(&self) -> Result<ThemeHistory, OpalineError>;
+
+    /// Persist `history`, replacing what was saved before.
+    fn save(&self, history: &ThemeHistory) -> Result<(), OpalineError>;
+}
+
+/// A [`ThemeHistoryStore`] backed by a TOML file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlHistoryStore {
+    path: PathBuf,
+}
+
+impl TomlHistoryStore {
+    /// Store history at `path`. Parent directories are created on save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// `theme-history.toml` in the Opaline config directory, or `None` if
+    /// the platform has no config directory.
+    #[cfg(feature = "discovery")]
+    pub fn in_config_dir() -> Option<Self> {
+        dirs::config_dir().map(|config| Self::new(config.join("opaline").join(FILE_NAME)))
+    }
+
+    /// `theme-history.toml` in an application's config directory, next to
+    /// its `themes/` folder from [`app_theme_dirs`](crate::app_theme_dirs).
+    #[cfg(feature = "discovery")]
+    pub fn for_app(app_name: &str) -> Option<Self> {
+        dirs::config_dir().map(|config| Self::new(config.join(app_name).join(FILE_NAME)))
+    }
+
+    /// The file this store reads and writes.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "discovery")]
+const FILE_NAME: &str = "theme-history.toml";
+
+impl ThemeHistoryStore for TomlHistoryStore {
+    fn load(&self) -> Result<ThemeHistory, OpalineError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(ThemeHistory::default());
+            }
+            Err(source) => {
+                return Err(OpalineError::Io {
+                    path: self.path.clone(),
+                    source,
+                });
+            }
+        };
+        toml::from_str(&contents).map_err(|source| OpalineError::Parse {
+            path: Some(self.path.clone()),
+            source,
+        })
+    }
+
+    fn save(&self, history: &ThemeHistory) -> Result<(), OpalineError> {
+        let write_error = |source| OpalineError::Write {
+            path: self.path.clone(),
+            source,
+        };
+        let contents = toml::to_string(history).map_err(|err| OpalineError::InvalidFormat {
+            format: "TOML".to_string(),
+            message: err.to_string(),
+        })?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(write_error)?;
+        }
+        std::fs::write(&self.path, contents).map_err(write_error)
+    }
+}
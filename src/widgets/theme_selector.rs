@@ -7,49 +7,64 @@
 //! # Usage
 //!
 //! ```rust,ignore
-//! // Open the selector
+//! // Open the selector, with favorites and recents pinned on top
 //! let state = ThemeSelectorState::with_current_selected()
-//!     .with_derive(my_app::derive_tokens);
+//!     .with_derive(my_app::derive_tokens)
+//!     .with_history(TomlHistoryStore::for_app("my-app").expect("config dir"));
 //!
-//! // In your key handler
+//! // In your key and mouse handlers
 //! match state.handle_key(key) {
 //!     ThemeSelectorAction::Select(id) => save_preference(&id),
 //!     ThemeSelectorAction::Cancel => { /* original theme restored */ },
 //!     _ => {}
 //! }
+//! let action = state.handle_mouse(mouse, area);
 //!
 //! // In your render
 //! frame.render_stateful_widget(ThemeSelector::new(), area, &mut state);
 //! ```
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, StatefulWidget, Widget};
 use unicode_width::UnicodeWidthStr;
 
+use super::theme_history::{ThemeHistory, ThemeHistoryStore};
 use crate::builtins::ThemeInfo;
 use crate::names::tokens;
 use crate::theme::Theme;
 use crate::{ThemeVariant, current, list_available_themes, set_theme};
 
+/// Two clicks on the same entry within this interval select it.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
 // ── Action ────────────────────────────────────────────────────────────
 
-/// Result of handling a key event in the theme selector.
+/// Result of handling a key or mouse event in the theme selector.
+///
+/// New actions may be added as the selector grows, so matches need a `_`
+/// arm.
+#[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ThemeSelectorAction {
     /// Cursor moved — live preview applied.
     Navigate,
-    /// Enter pressed — theme confirmed. Contains the theme's kebab-case ID.
+    /// Enter pressed or entry double-clicked — theme confirmed. Contains
+    /// the theme's kebab-case ID.
     Select(String),
     /// Esc pressed — original theme restored.
     Cancel,
     /// Filter text changed — list recomputed.
     FilterChanged,
+    /// Ctrl+F pressed — the selected theme was added to or removed from the
+    /// favorites.
+    FavoriteToggled,
     /// Key not handled by the selector.
     Noop,
 }
@@ -62,16 +77,33 @@ pub struct ThemeSelectorState {
     themes: Vec<ThemeInfo>,
     /// Pre-loaded themes for instant preview (indices match `themes`).
     theme_cache: Vec<Theme>,
-    /// Lowercase `(display_name, author)` pairs for fast filtering.
-    search_cache: Vec<(String, String)>,
+    /// Lowercased searchable fields, indices match `themes`.
+    search_cache: Vec<SearchEntry>,
     /// Current filter text.
     filter: String,
-    /// Indices into `themes` that match the current filter.
+    /// Indices into `themes` that match the current filter, best first
+    /// within each variant.
     filtered_indices: Vec<usize>,
+    /// Matched character positions in each filtered display name.
+    highlights: Vec<Vec<usize>>,
+    /// How many leading entries of `filtered_indices` are pinned favorites
+    /// and recents. Pinned themes also appear in their variant group.
+    pinned_count: usize,
+    /// Favorite and recently used theme ids.
+    history: ThemeHistory,
+    /// Where `history` is saved, if anywhere.
+    history_store: Option<Box<dyn ThemeHistoryStore>>,
     /// Cursor position within `filtered_indices`.
     cursor: usize,
     /// Scroll offset for the visible list.
     scroll: usize,
+    /// Screen row and filtered index of each list entry, from the last
+    /// render. Used to hit-test mouse clicks.
+    rendered_rows: Vec<(u16, usize)>,
+    /// The list pane from the last render.
+    list_area: Rect,
+    /// Time and filtered index of the last click, for double-clicks.
+    last_click: Option<(Instant, usize)>,
     /// Snapshot of the theme at open time, for Esc restore.
     original_theme: Arc<Theme>,
     /// Optional app-level token derivation callback.
@@ -102,12 +134,10 @@ impl ThemeSelectorState {
             .map(|info| info.load().unwrap_or_default())
             .collect();
 
-        let search_cache: Vec<(String, String)> = themes
-            .iter()
-            .map(|info| (info.display_name.to_lowercase(), info.author.to_lowercase()))
-            .collect();
+        let search_cache: Vec<SearchEntry> = themes.iter().map(SearchEntry::new).collect();
 
         let filtered_indices: Vec<usize> = (0..themes.len()).collect();
+        let highlights = vec![Vec::new(); themes.len()];
         let original_theme = current();
 
         Self {
@@ -116,8 +146,15 @@ impl ThemeSelectorState {
             search_cache,
             filter: String::new(),
             filtered_indices,
+            highlights,
+            pinned_count: 0,
+            history: ThemeHistory::default(),
+            history_store: None,
             cursor: 0,
             scroll: 0,
+            rendered_rows: Vec::new(),
+            list_area: Rect::default(),
+            last_click: None,
             original_theme,
             derive_fn: None,
         }
@@ -134,16 +171,32 @@ impl ThemeSelectorState {
             .iter()
             .position(|info| info.display_name == current_name || info.name == current_name)
         {
-            if let Some(cursor_pos) = state.filtered_indices.iter().position(|&i| i == pos) {
-                state.cursor = cursor_pos;
-                // Center the cursor in the viewport
-                state.scroll = cursor_pos.saturating_sub(8);
-            }
+            state.select_theme(pos);
         }
 
         state
     }
 
+    /// Load favorites and recently used themes from `store` and pin them
+    /// above the dark and light groups.
+    ///
+    /// Confirming a theme records it as recent, and Ctrl+F toggles the
+    /// selected theme as a favorite; both save back to `store`. A history
+    /// that fails to load starts empty, and failed saves are ignored so a
+    /// read-only config directory never breaks the picker.
+    #[must_use]
+    pub fn with_history(mut self, store: impl ThemeHistoryStore + 'static) -> Self {
+        self.history = store.load().unwrap_or_default();
+        self.history_store = Some(Box::new(store));
+
+        let selected = self.filtered_indices.get(self.cursor).copied();
+        self.rebuild_list();
+        if let Some(idx) = selected {
+            self.select_theme(idx);
+        }
+        self
+    }
+
     /// Set the app-level token derivation callback for live preview.
     ///
     /// This function is called on each cached theme clone before it's applied
@@ -157,48 +210,21 @@ impl ThemeSelectorState {
     /// Handle a key event. Returns the action taken.
     pub fn handle_key(&mut self, key: KeyEvent) -> ThemeSelectorAction {
         match key.code {
-            KeyCode::Up => {
-                if self.filtered_indices.is_empty() {
-                    return ThemeSelectorAction::Noop;
-                }
-                if self.cursor > 0 {
-                    self.cursor -= 1;
-                    if self.cursor < self.scroll {
-                        self.scroll = self.cursor;
-                    }
-                    self.apply_preview();
-                    ThemeSelectorAction::Navigate
-                } else {
-                    ThemeSelectorAction::Noop
-                }
-            }
-            KeyCode::Down => {
-                if self.filtered_indices.is_empty() {
-                    return ThemeSelectorAction::Noop;
-                }
-                if self.cursor + 1 < self.filtered_indices.len() {
-                    self.cursor += 1;
-                    // Scroll adjustment happens in render via clamp_scroll
-                    self.apply_preview();
-                    ThemeSelectorAction::Navigate
-                } else {
-                    ThemeSelectorAction::Noop
-                }
-            }
-            KeyCode::Enter => {
-                if let Some(&idx) = self.filtered_indices.get(self.cursor) {
-                    let id = self.themes[idx].name.clone();
-                    // Theme is already applied as preview — just confirm
-                    ThemeSelectorAction::Select(id)
-                } else {
-                    ThemeSelectorAction::Noop
-                }
-            }
+            KeyCode::Up => self.move_up(),
+            KeyCode::Down => self.move_down(),
+            KeyCode::Enter => self.confirm(),
             KeyCode::Esc => {
                 // Restore the original theme exactly
                 set_theme((*self.original_theme).clone());
                 ThemeSelectorAction::Cancel
             }
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if self.toggle_favorite().is_some() {
+                    ThemeSelectorAction::FavoriteToggled
+                } else {
+                    ThemeSelectorAction::Noop
+                }
+            }
             KeyCode::Char(c) => {
                 self.filter.push(c);
                 self.recompute_filter();
@@ -218,6 +244,44 @@ impl ThemeSelectorState {
         }
     }
 
+    /// Handle a mouse event over a selector rendered into `area`.
+    ///
+    /// Clicking an entry previews it, clicking it again within half a
+    /// second selects it, and the scroll wheel moves the cursor.
+    ///
+    /// `area` only filters events: anything outside it, including the wheel,
+    /// is ignored. Rows are hit-tested against the layout recorded by the
+    /// last render, so pass the same `area` the widget was drawn in.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect) -> ThemeSelectorAction {
+        let position = Position::new(mouse.column, mouse.row);
+        if !area.contains(position) {
+            return ThemeSelectorAction::Noop;
+        }
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.move_up(),
+            MouseEventKind::ScrollDown => self.move_down(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(filter_idx) = self.entry_at(position) else {
+                    self.last_click = None;
+                    return ThemeSelectorAction::Noop;
+                };
+                let now = Instant::now();
+                let double = self.last_click.is_some_and(|(at, idx)| {
+                    idx == filter_idx && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+                });
+                self.cursor = filter_idx;
+                if double {
+                    self.last_click = None;
+                    return self.confirm();
+                }
+                self.last_click = Some((now, filter_idx));
+                self.apply_preview();
+                ThemeSelectorAction::Navigate
+            }
+            _ => ThemeSelectorAction::Noop,
+        }
+    }
+
     /// The currently selected theme info, if any.
     pub fn selected_theme(&self) -> Option<&ThemeInfo> {
         self.filtered_indices
@@ -230,26 +294,168 @@ impl ThemeSelectorState {
         &self.filter
     }
 
+    /// Favorite and recently used themes.
+    pub fn history(&self) -> &ThemeHistory {
+        &self.history
+    }
+
+    /// Add or remove the selected theme from the favorites and save the
+    /// history. Returns whether it's now a favorite, or `None` if nothing is
+    /// selected.
+    pub fn toggle_favorite(&mut self) -> Option<bool> {
+        let idx = *self.filtered_indices.get(self.cursor)?;
+        let in_pinned = self.cursor < self.pinned_count;
+        let favorite = self.history.toggle_favorite(&self.themes[idx].name);
+        self.save_history();
+
+        // Keep the cursor on the same theme as the pinned section changes.
+        let scroll = self.scroll;
+        self.rebuild_list();
+        let pos = self
+            .filtered_indices
+            .iter()
+            .enumerate()
+            .position(|(i, &t)| t == idx && (in_pinned || i >= self.pinned_count));
+        self.cursor = pos.unwrap_or(0);
+        self.scroll = scroll;
+        Some(favorite)
+    }
+
+    /// Themes matching the current filter, in display order. Pinned themes
+    /// come first and appear again in their variant group.
+    pub fn filtered_themes(&self) -> impl Iterator<Item = &ThemeInfo> {
+        self.filtered_indices.iter().map(|&idx| &self.themes[idx])
+    }
+
     // ── Internal ──────────────────────────────────────────────────────
 
-    fn recompute_filter(&mut self) {
-        let query = self.filter.to_lowercase();
-        self.filtered_indices = if query.is_empty() {
-            (0..self.themes.len()).collect()
-        } else {
-            self.search_cache
-                .iter()
-                .enumerate()
-                .filter(|(_, (name, author))| name.contains(&query) || author.contains(&query))
-                .map(|(i, _)| i)
-                .collect()
+    /// Confirm the theme under the cursor and record it as recently used.
+    fn confirm(&mut self) -> ThemeSelectorAction {
+        let Some(&idx) = self.filtered_indices.get(self.cursor) else {
+            return ThemeSelectorAction::Noop;
         };
+        let id = self.themes[idx].name.clone();
+        self.history.record_use(&id);
+        self.save_history();
+        // Theme is already applied as preview — just confirm
+        ThemeSelectorAction::Select(id)
+    }
+
+    fn save_history(&self) {
+        if let Some(store) = &self.history_store {
+            // Best effort; see `with_history`.
+            let _ = store.save(&self.history);
+        }
+    }
+
+    /// Put the cursor on the first entry for `themes[idx]`.
+    fn select_theme(&mut self, idx: usize) {
+        if let Some(cursor_pos) = self.filtered_indices.iter().position(|&i| i == idx) {
+            self.cursor = cursor_pos;
+            // Center the cursor in the viewport
+            self.scroll = cursor_pos.saturating_sub(8);
+        }
+    }
+
+    fn section(&self, filter_idx: usize) -> Section {
+        if filter_idx < self.pinned_count {
+            Section::Pinned
+        } else {
+            Section::Variant(self.themes[self.filtered_indices[filter_idx]].variant)
+        }
+    }
+
+    fn move_up(&mut self) -> ThemeSelectorAction {
+        if self.filtered_indices.is_empty() || self.cursor == 0 {
+            return ThemeSelectorAction::Noop;
+        }
+        self.cursor -= 1;
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        }
+        self.apply_preview();
+        ThemeSelectorAction::Navigate
+    }
+
+    fn move_down(&mut self) -> ThemeSelectorAction {
+        if self.cursor + 1 >= self.filtered_indices.len() {
+            return ThemeSelectorAction::Noop;
+        }
+        self.cursor += 1;
+        // Scroll adjustment happens in render via clamp_scroll
+        self.apply_preview();
+        ThemeSelectorAction::Navigate
+    }
+
+    /// The filtered index of the entry drawn at `position`, if any.
+    fn entry_at(&self, position: Position) -> Option<usize> {
+        if !self.list_area.contains(position) {
+            return None;
+        }
+        self.rendered_rows
+            .iter()
+            .find(|&&(y, _)| y == position.y)
+            .map(|&(_, idx)| idx)
+    }
+
+    fn recompute_filter(&mut self) {
+        self.rebuild_list();
 
         // Reset cursor to first match
         self.cursor = 0;
         self.scroll = 0;
     }
 
+    /// Recompute the pinned section and filtered list. Rendered rows are
+    /// stale until the next render, and entries may have moved, so a pending
+    /// click can't pair with the next one into a double-click.
+    fn rebuild_list(&mut self) {
+        self.last_click = None;
+        let query = Query::parse(&self.filter);
+        let mut matches: Vec<(usize, u32, Vec<usize>)> = self
+            .search_cache
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| query.variant.is_none_or(|v| self.themes[i].variant == v))
+            .filter_map(|(i, entry)| {
+                query
+                    .score(entry)
+                    .map(|(score, highlights)| (i, score, highlights))
+            })
+            .collect();
+
+        // Keep the dark/light grouping, best match first within each.
+        matches.sort_by(|a, b| {
+            variant_rank(self.themes[a.0].variant)
+                .cmp(&variant_rank(self.themes[b.0].variant))
+                .then(b.1.cmp(&a.1))
+                .then(a.0.cmp(&b.0))
+        });
+
+        // Favorites, then recents, in history order.
+        let mut pinned: Vec<(usize, Vec<usize>)> = Vec::new();
+        for id in self.history.favorites.iter().chain(&self.history.recent) {
+            let Some((i, _, highlights)) = matches.iter().find(|m| self.themes[m.0].name == *id)
+            else {
+                continue;
+            };
+            if !pinned.iter().any(|(p, _)| p == i) {
+                pinned.push((*i, highlights.clone()));
+            }
+        }
+        self.pinned_count = pinned.len();
+
+        (self.filtered_indices, self.highlights) = pinned
+            .into_iter()
+            .chain(
+                matches
+                    .into_iter()
+                    .map(|(i, _, highlights)| (i, highlights)),
+            )
+            .unzip();
+        self.rendered_rows.clear();
+    }
+
     fn apply_preview(&self) {
         if let Some(&idx) = self.filtered_indices.get(self.cursor) {
             let mut theme = self.theme_cache[idx].clone();
@@ -280,6 +486,177 @@ impl Default for ThemeSelectorState {
     }
 }
 
+// ── Filtering ─────────────────────────────────────────────────────────
+
+/// Score for each matched character.
+const MATCH_SCORE: u32 = 1;
+/// Bonus for a match that directly follows the previous one.
+const CONSECUTIVE_BONUS: u32 = 5;
+/// Bonus for a match at the start of a word.
+const WORD_START_BONUS: u32 = 8;
+
+/// Lowercased searchable fields for one theme. One `char` per source
+/// `char`, so match positions map straight back onto the display name.
+struct SearchEntry {
+    name: Vec<char>,
+    id: Vec<char>,
+    author: Vec<char>,
+    description: String,
+}
+
+impl SearchEntry {
+    fn new(info: &ThemeInfo) -> Self {
+        Self {
+            name: fold(&info.display_name),
+            id: fold(&info.name),
+            author: fold(&info.author),
+            description: info.description.to_lowercase(),
+        }
+    }
+}
+
+/// A parsed filter string.
+///
+/// Whitespace-separated terms must all match. `dark:` and `light:` limit
+/// the variant, and `author:name` matches only the author; anything after
+/// `dark:`/`light:` is a regular term. Regular terms fuzzy-match the display
+/// name, kebab-case id, or author, or appear in the description.
+#[derive(Debug, Default)]
+struct Query {
+    variant: Option<ThemeVariant>,
+    terms: Vec<Vec<char>>,
+    author_terms: Vec<Vec<char>>,
+}
+
+impl Query {
+    fn parse(filter: &str) -> Self {
+        let mut query = Self::default();
+        for word in filter.split_whitespace() {
+            let lower = word.to_lowercase();
+            let term = if let Some(rest) = lower.strip_prefix("dark:") {
+                query.variant = Some(ThemeVariant::Dark);
+                rest
+            } else if let Some(rest) = lower.strip_prefix("light:") {
+                query.variant = Some(ThemeVariant::Light);
+                rest
+            } else if let Some(rest) = lower.strip_prefix("author:") {
+                if !rest.is_empty() {
+                    query.author_terms.push(rest.chars().collect());
+                }
+                continue;
+            } else {
+                &lower
+            };
+            if !term.is_empty() {
+                query.terms.push(term.chars().collect());
+            }
+        }
+        query
+    }
+
+    /// Total score and display-name highlights, or `None` if a term misses.
+    fn score(&self, entry: &SearchEntry) -> Option<(u32, Vec<usize>)> {
+        let mut total = 0;
+        let mut highlights = Vec::new();
+
+        for term in &self.author_terms {
+            total += fuzzy_match(term, &entry.author)?.0;
+        }
+        for term in &self.terms {
+            let name = fuzzy_match(term, &entry.name);
+            let other = [
+                fuzzy_match(term, &entry.id).map(|(score, _)| score),
+                fuzzy_match(term, &entry.author).map(|(score, _)| score / 2),
+                entry
+                    .description
+                    .contains(&term.iter().collect::<String>())
+                    .then_some(MATCH_SCORE),
+            ]
+            .into_iter()
+            .flatten()
+            .max();
+
+            match (name, other) {
+                (Some((score, _)), Some(other)) if other > score => total += other,
+                (Some((score, positions)), _) => {
+                    total += score;
+                    highlights.extend(positions);
+                }
+                (None, Some(score)) => total += score,
+                (None, None) => return None,
+            }
+        }
+
+        highlights.sort_unstable();
+        highlights.dedup();
+        Some((total, highlights))
+    }
+}
+
+/// Match `query` as a subsequence of `target`, returning a score and the
+/// matched positions. Every start for the first character is tried, so
+/// word-start and consecutive runs win over the leftmost scatter.
+fn fuzzy_match(query: &[char], target: &[char]) -> Option<(u32, Vec<usize>)> {
+    let Some((&first, rest)) = query.split_first() else {
+        return Some((0, Vec::new()));
+    };
+
+    let mut best: Option<(u32, Vec<usize>)> = None;
+    for start in (0..target.len()).filter(|&i| target[i] == first) {
+        let mut positions = vec![start];
+        let mut next = start + 1;
+        for &c in rest {
+            let Some(offset) = target[next..].iter().position(|&t| t == c) else {
+                // Later starts only see a suffix of this one, so they miss too.
+                return best;
+            };
+            positions.push(next + offset);
+            next += offset + 1;
+        }
+
+        let score = positions
+            .iter()
+            .enumerate()
+            .map(|(k, &pos)| {
+                let mut score = MATCH_SCORE;
+                if pos == 0 || !target[pos - 1].is_alphanumeric() {
+                    score += WORD_START_BONUS;
+                }
+                if k > 0 && positions[k - 1] + 1 == pos {
+                    score += CONSECUTIVE_BONUS;
+                }
+                score
+            })
+            .sum();
+        if best.as_ref().is_none_or(|(b, _)| score > *b) {
+            best = Some((score, positions));
+        }
+    }
+    best
+}
+
+/// Lowercase `text` one `char` at a time, keeping positions aligned.
+fn fold(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// A group of entries in the list, each under its own header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Pinned,
+    Variant(ThemeVariant),
+}
+
+/// Sort key that puts dark themes before light ones.
+const fn variant_rank(variant: ThemeVariant) -> u8 {
+    match variant {
+        ThemeVariant::Dark => 0,
+        ThemeVariant::Light => 1,
+    }
+}
+
 // ── Widget ────────────────────────────────────────────────────────────
 
 /// The theme selector widget. Renders a two-pane view: searchable list +
@@ -427,7 +804,7 @@ fn render_theme_list(area: Rect, buf: &mut Buffer, state: &mut ThemeSelectorStat
 fn render_theme_entries(
     list_area: Rect,
     buf: &mut Buffer,
-    state: &ThemeSelectorState,
+    state: &mut ThemeSelectorState,
     accent_secondary: Color,
     text_primary: Color,
     text_muted: Color,
@@ -436,7 +813,8 @@ fn render_theme_entries(
     let mut y = list_area.y;
     let max_y = list_area.y + list_area.height;
     let mut items_rendered = 0;
-    let mut last_variant: Option<ThemeVariant> = None;
+    let mut last_section: Option<Section> = None;
+    let mut rendered_rows = Vec::new();
 
     for (filter_idx, &theme_idx) in state.filtered_indices.iter().enumerate() {
         if y >= max_y {
@@ -446,11 +824,13 @@ fn render_theme_entries(
         let info = &state.themes[theme_idx];
 
         // Section header on variant boundary
-        if last_variant != Some(info.variant) {
-            if (items_rendered >= state.scroll || last_variant.is_none()) && y < max_y {
-                let header_text = match info.variant {
-                    ThemeVariant::Dark => " Dark Themes",
-                    ThemeVariant::Light => " Light Themes",
+        let section = state.section(filter_idx);
+        if last_section != Some(section) {
+            if (items_rendered >= state.scroll || last_section.is_none()) && y < max_y {
+                let header_text = match section {
+                    Section::Pinned => " Pinned",
+                    Section::Variant(ThemeVariant::Dark) => " Dark Themes",
+                    Section::Variant(ThemeVariant::Light) => " Light Themes",
                 };
                 let header = Line::from(Span::styled(
                     header_text,
@@ -461,7 +841,7 @@ fn render_theme_entries(
                 header.render(Rect::new(list_area.x, y, list_area.width, 1), buf);
                 y += 1;
             }
-            last_variant = Some(info.variant);
+            last_section = Some(section);
         }
 
         // Skip items before scroll window
@@ -477,25 +857,32 @@ fn render_theme_entries(
         let is_selected = filter_idx == state.cursor;
 
         let mut spans = vec![];
-        if is_selected {
+        let name_style = if is_selected {
             spans.push(Span::styled(
                 "  > ",
                 Style::default()
                     .fg(accent_secondary)
                     .add_modifier(Modifier::BOLD),
             ));
-            spans.push(Span::styled(
-                &info.display_name,
-                Style::default()
-                    .fg(accent_secondary)
-                    .add_modifier(Modifier::BOLD),
-            ));
+            Style::default()
+                .fg(accent_secondary)
+                .add_modifier(Modifier::BOLD)
         } else {
             spans.push(Span::raw("    "));
-            spans.push(Span::styled(
-                &info.display_name,
-                Style::default().fg(text_primary),
-            ));
+            Style::default().fg(text_primary)
+        };
+        let match_style = name_style
+            .fg(accent_secondary)
+            .add_modifier(Modifier::UNDERLINED);
+        spans.extend(highlighted_spans(
+            &info.display_name,
+            &state.highlights[filter_idx],
+            name_style,
+            match_style,
+        ));
+
+        if state.history.is_favorite(&info.name) {
+            spans.push(Span::styled(" \u{2605}", Style::default().fg(warning)));
         }
 
         // Light theme indicator
@@ -504,21 +891,54 @@ fn render_theme_entries(
         }
 
         Line::from(spans).render(Rect::new(list_area.x, y, list_area.width, 1), buf);
+        rendered_rows.push((y, filter_idx));
 
         y += 1;
         items_rendered += 1;
     }
+
+    state.rendered_rows = rendered_rows;
+    state.list_area = list_area;
+}
+
+/// Split `text` into spans, styling the characters at `positions` with
+/// `highlight`.
+fn highlighted_spans<'a>(
+    text: &'a str,
+    positions: &[usize],
+    base: Style,
+    highlight: Style,
+) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut run_start = 0;
+    let mut run_highlighted = false;
+    for (i, (byte, _)) in text.char_indices().enumerate() {
+        let highlighted = positions.binary_search(&i).is_ok();
+        if highlighted != run_highlighted {
+            if byte > run_start {
+                let style = if run_highlighted { highlight } else { base };
+                spans.push(Span::styled(&text[run_start..byte], style));
+            }
+            run_start = byte;
+            run_highlighted = highlighted;
+        }
+    }
+    if run_start < text.len() {
+        let style = if run_highlighted { highlight } else { base };
+        spans.push(Span::styled(&text[run_start..], style));
+    }
+    spans
 }
 
 /// Count how many section headers will appear in the filtered list.
 fn count_section_headers(state: &ThemeSelectorState) -> usize {
     let mut count = 0;
-    let mut last_variant: Option<ThemeVariant> = None;
-    for &idx in &state.filtered_indices {
-        let variant = state.themes[idx].variant;
-        if last_variant != Some(variant) {
+    let mut last_section: Option<Section> = None;
+    for filter_idx in 0..state.filtered_indices.len() {
+        let section = state.section(filter_idx);
+        if last_section != Some(section) {
             count += 1;
-            last_variant = Some(variant);
+            last_section = Some(section);
         }
     }
     count
@@ -754,4 +1174,55 @@ mod tests {
         let result = wrap_text("é é", 3);
         assert_eq!(result, vec!["é é"]);
     }
+
+    #[test]
+    fn fuzzy_match_prefers_word_starts_and_runs() {
+        let target = fold("Tokyo Night Storm");
+        let (_, positions) = fuzzy_match(&fold("tns"), &target).expect("match");
+        assert_eq!(positions, vec![0, 6, 12]);
+
+        let (_, positions) = fuzzy_match(&fold("night"), &target).expect("match");
+        assert_eq!(positions, vec![6, 7, 8, 9, 10]);
+
+        assert!(fuzzy_match(&fold("xyz"), &target).is_none());
+        assert!(fuzzy_match(&fold("stn"), &target).is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_scores_tighter_matches_higher() {
+        let query = fold("mo");
+        let (tight, _) = fuzzy_match(&query, &fold("Catppuccin Mocha")).expect("match");
+        let (loose, _) = fuzzy_match(&query, &fold("Gruvbox Dark Mode")).expect("match");
+        let (scattered, _) = fuzzy_match(&query, &fold("Tomorrow")).expect("match");
+        assert_eq!(tight, loose);
+        assert!(tight > scattered);
+    }
+
+    #[test]
+    fn query_parses_prefixes() {
+        let query = Query::parse("dark:rose  AUTHOR:Pine night");
+        assert_eq!(query.variant, Some(ThemeVariant::Dark));
+        assert_eq!(query.terms, vec![fold("rose"), fold("night")]);
+        assert_eq!(query.author_terms, vec![fold("pine")]);
+
+        let query = Query::parse("light: author:");
+        assert_eq!(query.variant, Some(ThemeVariant::Light));
+        assert_eq!(query.terms.len(), 0);
+        assert_eq!(query.author_terms.len(), 0);
+    }
+
+    #[test]
+    fn highlighted_spans_group_runs() {
+        let base = Style::new();
+        let hit = Style::new().add_modifier(Modifier::UNDERLINED);
+        let spans = highlighted_spans("Rosé Pine", &[0, 1, 5], base, hit);
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style == hit))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![("Ro", true), ("sé ", false), ("P", true), ("ine", false)]
+        );
+    }
 }
@@ -1,6 +1,12 @@
-use opaline::{Gradient, OpalineColor, OpalineStyle};
+use opaline::{
+    Gradient, GradientDirection, GradientFill, OpalineColor, OpalineStyle, fill_gradient,
+    gradient_multiline,
+};
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
 use ratatui_core::style::{Color, Modifier, Style, Styled};
 use ratatui_core::text::{Line, Span, Text};
+use ratatui_core::widgets::Widget;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // Color → ratatui conversions
@@ -445,6 +451,128 @@ fn default_theme_ratatui_integration() {
     let _text: Text = theme.text("keyword", "test");
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// Gradient fills
+// ═══════════════════════════════════════════════════════════════════════════════
+
+const RED: OpalineColor = OpalineColor::new(255, 0, 0);
+const BLUE: OpalineColor = OpalineColor::new(0, 0, 255);
+
+fn fill(fill: GradientFill<'_>, width: u16, height: u16) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+    fill.render(buf.area, &mut buf);
+    buf
+}
+
+#[test]
+fn gradient_fill_directions() {
+    let grad = Gradient::new(vec![RED, BLUE]);
+    let at = |t: f32| Color::from(grad.at(t));
+
+    let buf = fill(GradientFill::new(&grad), 5, 3);
+    assert_eq!(buf[(0, 2)].bg, at(0.0));
+    assert_eq!(buf[(2, 0)].bg, at(0.5));
+    assert_eq!(buf[(4, 1)].bg, at(1.0));
+
+    let buf = fill(
+        GradientFill::new(&grad).direction(GradientDirection::Vertical),
+        5,
+        3,
+    );
+    assert_eq!(buf[(4, 0)].bg, at(0.0));
+    assert_eq!(buf[(0, 2)].bg, at(1.0));
+
+    let buf = fill(
+        GradientFill::new(&grad).direction(GradientDirection::Diagonal),
+        5,
+        3,
+    );
+    assert_eq!(buf[(0, 0)].bg, at(0.0));
+    assert_eq!(buf[(4, 0)].bg, at(0.5));
+    assert_eq!(buf[(4, 2)].bg, at(1.0));
+
+    let buf = fill(
+        GradientFill::new(&grad).direction(GradientDirection::Radial),
+        5,
+        3,
+    );
+    assert_eq!(buf[(2, 1)].bg, at(0.0));
+    assert_eq!(buf[(0, 0)].bg, at(1.0));
+    assert_eq!(buf[(4, 2)].bg, at(1.0));
+}
+
+#[test]
+fn gradient_fill_keeps_symbols_and_foreground() {
+    let grad = Gradient::new(vec![RED, BLUE]);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+    buf.set_string(0, 0, "Hi", Style::default().fg(Color::Green));
+
+    fill_gradient(
+        &mut buf,
+        Rect::new(0, 0, 4, 1),
+        &grad,
+        GradientDirection::Horizontal,
+    );
+    assert_eq!(buf[(0, 0)].symbol(), "H");
+    assert_eq!(buf[(0, 0)].fg, Color::Green);
+    assert_eq!(buf[(0, 0)].bg, Color::from(RED));
+    assert_eq!(buf[(3, 0)].symbol(), " ");
+}
+
+#[test]
+fn gradient_fill_half_blocks_double_vertical_resolution() {
+    let grad = Gradient::new(vec![RED, BLUE]);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+    buf.set_string(1, 1, "x", Style::default());
+    GradientFill::new(&grad)
+        .direction(GradientDirection::Vertical)
+        .half_blocks(true)
+        .render(buf.area, &mut buf);
+
+    // Four rows of samples: 0, 1/3, 2/3, 1.
+    let top = &buf[(0, 0)];
+    assert_eq!(top.symbol(), "\u{2580}");
+    assert_eq!(top.fg, Color::from(grad.at(0.0)));
+    assert_eq!(top.bg, Color::from(grad.at(1.0 / 3.0)));
+
+    let text = &buf[(1, 1)];
+    assert_eq!(text.symbol(), "x");
+    assert_eq!(text.bg, Color::from(grad.at(f32::midpoint(2.0 / 3.0, 1.0))));
+}
+
+#[test]
+fn gradient_fill_half_blocks_on_very_tall_areas() {
+    let grad = Gradient::new(vec![RED, BLUE]);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 1, 40_000));
+    GradientFill::new(&grad)
+        .direction(GradientDirection::Vertical)
+        .half_blocks(true)
+        .render(buf.area, &mut buf);
+
+    assert_eq!(buf[(0, 0)].fg, Color::from(RED));
+    assert_eq!(buf[(0, 39_999)].bg, Color::from(BLUE));
+}
+
+#[test]
+fn theme_gradient_fill_by_name() {
+    let theme = test_theme();
+    let buf = fill(theme.gradient_fill("sunset"), 3, 1);
+    assert_eq!(buf[(0, 0)].bg, Color::Rgb(255, 0, 0));
+    assert_eq!(buf[(2, 0)].bg, Color::Rgb(255, 255, 0));
+
+    let buf = fill(theme.gradient_fill("nonexistent"), 3, 1);
+    assert_eq!(buf[(1, 0)].bg, Color::Reset);
+}
+
+#[test]
+fn gradient_fill_clips_to_buffer() {
+    let grad = Gradient::new(vec![RED, BLUE]);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+    GradientFill::new(&grad).render(Rect::new(1, 1, 10, 10), &mut buf);
+    assert_eq!(buf[(1, 1)].bg, Color::from(RED));
+    assert_eq!(buf[(0, 0)].bg, Color::Reset);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Modifier bits correctness (internal, but tested via public API)
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -462,3 +590,129 @@ fn modifier_bits_match_ratatui() {
     assert_eq!(Modifier::HIDDEN.bits(), 0b0000_1000_0000);
     assert_eq!(Modifier::CROSSED_OUT.bits(), 0b0001_0000_0000);
 }
+
+#[test]
+fn gradient_spans_at_phase_scroll_right() {
+    let grad = Gradient::new(vec![
+        OpalineColor::new(255, 0, 0),
+        OpalineColor::new(0, 0, 255),
+    ]);
+    let still = opaline::gradient_spans_at_phase("abcd", &grad, 0.0);
+    let moved = opaline::gradient_spans_at_phase("abcd", &grad, 0.25);
+    assert_eq!(still.len(), 4);
+    assert_eq!(still[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+    assert_eq!(still[2].style.fg, Some(Color::Rgb(0, 0, 255)));
+    assert_eq!(moved[1].style.fg, still[0].style.fg);
+    assert_eq!(moved[3].style.fg, still[2].style.fg);
+    assert_eq!(moved[1].content, "b");
+}
+
+#[test]
+fn gradient_bar_at_phase_wraps() {
+    let grad = Gradient::new(vec![
+        OpalineColor::new(255, 0, 0),
+        OpalineColor::new(0, 0, 255),
+    ]);
+    let line = opaline::gradient_bar_at_phase(6, '\u{2588}', &grad, 0.5);
+    assert_eq!(line.spans.len(), 6);
+    assert_eq!(line.spans[0].style.fg, Some(Color::Rgb(0, 0, 255)));
+    assert_eq!(line.spans[3].style.fg, Some(Color::Rgb(255, 0, 0)));
+    assert_eq!(opaline::gradient_line_at_phase(0, '-', &grad, 0.5).len(), 0);
+}
+
+#[test]
+fn theme_gradient_text_at_phase() {
+    let theme = opaline::Theme::builder("Phase")
+        .gradient(
+            "sunset",
+            Gradient::new(vec![
+                OpalineColor::new(255, 0, 0),
+                OpalineColor::new(0, 0, 255),
+            ]),
+        )
+        .build();
+    let line = theme.gradient_text_at_phase("sunset", "ab", 0.5);
+    assert_eq!(line.spans[0].style.fg, Some(Color::Rgb(0, 0, 255)));
+
+    let plain = theme.gradient_text_at_phase("missing", "ab", 0.5);
+    assert_eq!(plain.spans.len(), 1);
+    assert_eq!(plain.spans[0].style.fg, None);
+}
+
+#[test]
+fn gradient_multiline_counts_display_columns() {
+    let grad = Gradient::new(vec![
+        OpalineColor::new(0, 0, 0),
+        OpalineColor::new(255, 255, 255),
+    ]);
+    // "日本" is four columns wide, "ab" two.
+    let text = gradient_multiline("日本\nab", &grad, GradientDirection::Horizontal);
+    assert_eq!(text.lines.len(), 2);
+
+    let top = &text.lines[0].spans;
+    assert_eq!(top[0].content, "日");
+    assert_eq!(top[0].style.fg, Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(top[1].style.fg, Some(Color::Rgb(170, 170, 170)));
+
+    let bottom = &text.lines[1].spans;
+    assert_eq!(bottom[1].style.fg, Some(Color::Rgb(85, 85, 85)));
+}
+
+#[test]
+fn gradient_multiline_vertical_and_diagonal() {
+    let grad = Gradient::new(vec![
+        OpalineColor::new(0, 0, 0),
+        OpalineColor::new(255, 255, 255),
+    ]);
+    let text = gradient_multiline("ab\ncd\nef", &grad, GradientDirection::Vertical);
+    assert_eq!(text.lines[0].spans[1].style.fg, Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(
+        text.lines[1].spans[0].style.fg,
+        Some(Color::Rgb(128, 128, 128))
+    );
+    assert_eq!(
+        text.lines[2].spans[1].style.fg,
+        Some(Color::Rgb(255, 255, 255))
+    );
+
+    let text = gradient_multiline("ab\ncd", &grad, GradientDirection::Diagonal);
+    assert_eq!(text.lines[0].spans[0].style.fg, Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(
+        text.lines[0].spans[1].style.fg,
+        Some(Color::Rgb(128, 128, 128))
+    );
+    assert_eq!(
+        text.lines[1].spans[1].style.fg,
+        Some(Color::Rgb(255, 255, 255))
+    );
+}
+
+#[test]
+fn gradient_multiline_keeps_styles() {
+    let grad = Gradient::new(vec![OpalineColor::new(9, 9, 9)]);
+    let input = Text::from(vec![
+        Line::from(vec![
+            Span::styled("hi", Style::new().bold().bg(Color::Blue)),
+            Span::raw("!"),
+        ])
+        .centered(),
+    ]);
+    let text = gradient_multiline(input, &grad, GradientDirection::Horizontal);
+    let line = &text.lines[0];
+    assert_eq!(line.spans.len(), 3);
+    assert_eq!(
+        line.alignment,
+        Some(ratatui_core::layout::Alignment::Center)
+    );
+    assert!(line.spans[1].style.add_modifier.contains(Modifier::BOLD));
+    assert_eq!(line.spans[1].style.bg, Some(Color::Blue));
+    assert_eq!(line.spans[2].style.fg, Some(Color::Rgb(9, 9, 9)));
+}
+
+#[test]
+fn theme_gradient_multiline_falls_back_to_plain() {
+    let theme = opaline::Theme::builder("Plain").build();
+    let text = theme.gradient_multiline("missing", "a\nb", GradientDirection::Vertical);
+    assert_eq!(text.lines.len(), 2);
+    assert_eq!(text.lines[0].spans[0].style.fg, None);
+}
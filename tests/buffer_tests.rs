@@ -0,0 +1,157 @@
+#![cfg(feature = "ratatui")]
+
+use opaline::adapters::buffer::{
+    buffer_to_ansi, buffer_to_html, buffer_to_svg, desaturate, dim, map_colors, tint,
+};
+use opaline::{OpalineColor, Theme};
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Modifier, Style};
+
+fn theme() -> Theme {
+    Theme::builder("Buffer")
+        .token("text.primary", OpalineColor::new(248, 248, 242))
+        .token("bg.base", OpalineColor::new(18, 18, 24))
+        .build()
+}
+
+fn sample() -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+    buf.set_string(
+        0,
+        0,
+        "ab",
+        Style::new()
+            .fg(Color::Rgb(225, 53, 255))
+            .add_modifier(Modifier::BOLD),
+    );
+    buf.set_string(2, 0, "cd", Style::new());
+    buf.set_string(0, 1, "x<y", Style::new().bg(Color::Indexed(196)));
+    buf
+}
+
+#[test]
+fn ansi_merges_runs_and_resets() {
+    let ansi = buffer_to_ansi(&sample());
+    let lines: Vec<&str> = ansi.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "\x1b[0;1;38;2;225;53;255mab\x1b[0mcd  ");
+    assert_eq!(lines[1], "\x1b[0;48;5;196mx<y\x1b[0m   ");
+}
+
+#[test]
+fn ansi_plain_buffer_has_no_escapes() {
+    let buf = Buffer::with_lines(["hello", "world"]);
+    assert_eq!(buffer_to_ansi(&buf), "hello\nworld");
+}
+
+#[test]
+fn ansi_named_colors() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+    buf.set_string(0, 0, "r", Style::new().fg(Color::Red));
+    buf.set_string(
+        1,
+        0,
+        "c",
+        Style::new().fg(Color::LightCyan).bg(Color::Black),
+    );
+    assert_eq!(buffer_to_ansi(&buf), "\x1b[0;31mr\x1b[0;96;40mc\x1b[0m");
+}
+
+#[test]
+fn wide_characters_skip_trailing_cells() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+    buf.set_string(0, 0, "日x", Style::new());
+    assert_eq!(buffer_to_ansi(&buf), "日x ");
+}
+
+#[test]
+fn html_uses_theme_defaults_and_escapes() {
+    let html = buffer_to_html(&sample(), &theme());
+    assert!(html.starts_with("<pre style="));
+    assert!(html.contains("color: #f8f8f2; background: #121218"));
+    assert!(html.contains("<span style=\"color: #e135ff; font-weight: bold\">ab</span>cd  \n"));
+    assert!(html.contains("<span style=\"background: #ff0000\">x&lt;y</span>"));
+    assert!(html.trim_end().ends_with("</pre>"));
+}
+
+#[test]
+fn reversed_swaps_colors() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+    buf.set_string(
+        0,
+        0,
+        "r",
+        Style::new()
+            .fg(Color::Rgb(1, 2, 3))
+            .add_modifier(Modifier::REVERSED),
+    );
+    let html = buffer_to_html(&buf, &theme());
+    assert!(html.contains("<span style=\"color: #121218; background: #010203\">r</span>"));
+}
+
+#[test]
+fn svg_grid() {
+    let svg = buffer_to_svg(&sample(), &theme());
+    assert!(
+        svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"54\" height=\"36\"")
+    );
+    assert!(svg.contains("<rect x=\"0\" y=\"18\" width=\"27\" height=\"18\" fill=\"#ff0000\"/>"));
+    assert!(svg.contains(
+        "<text x=\"0\" y=\"13\" textLength=\"18\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#e135ff\" font-weight=\"bold\">ab</text>"
+    ));
+    assert!(svg.contains(">x&lt;y</text>"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn dim_fades_toward_background() {
+    let theme = theme();
+    let mut buf = sample();
+    dim(&mut buf, Rect::new(0, 0, 6, 2), &theme, 1.0);
+    for cell in buf.content() {
+        assert_eq!(cell.fg, Color::Rgb(18, 18, 24));
+        assert_eq!(cell.bg, Color::Rgb(18, 18, 24));
+    }
+}
+
+#[test]
+fn filters_resolve_reset_and_indexed_colors() {
+    let theme = theme();
+    let mut buf = sample();
+    map_colors(&mut buf, Rect::new(0, 0, 6, 2), &theme, |color| color);
+
+    assert_eq!(buf[(0, 0)].fg, Color::Rgb(225, 53, 255));
+    assert_eq!(buf[(2, 0)].fg, Color::Rgb(248, 248, 242));
+    assert_eq!(buf[(2, 0)].bg, Color::Rgb(18, 18, 24));
+    assert_eq!(buf[(0, 1)].bg, Color::Rgb(255, 0, 0));
+    assert_eq!(buf[(0, 0)].symbol(), "a");
+    assert!(buf[(0, 0)].modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn filters_stay_inside_the_area() {
+    let theme = theme();
+    let mut buf = sample();
+    let white = OpalineColor::new(255, 255, 255);
+    tint(&mut buf, Rect::new(1, 0, 1, 1), &theme, white, 1.0);
+
+    assert_eq!(buf[(1, 0)].fg, Color::Rgb(255, 255, 255));
+    assert_eq!(buf[(0, 0)].fg, Color::Rgb(225, 53, 255));
+    assert_eq!(buf[(2, 0)].fg, Color::Reset);
+
+    // Areas hanging off the buffer are clipped.
+    tint(&mut buf, Rect::new(4, 1, 10, 10), &theme, white, 1.0);
+    assert_eq!(buf[(5, 1)].bg, Color::Rgb(255, 255, 255));
+}
+
+#[test]
+fn desaturate_grays_colors() {
+    let theme = theme();
+    let mut buf = sample();
+    desaturate(&mut buf, Rect::new(0, 0, 6, 2), &theme, 1.0);
+    let Color::Rgb(r, g, b) = buf[(0, 0)].fg else {
+        panic!("expected RGB, got {:?}", buf[(0, 0)].fg);
+    };
+    assert_eq!((r, r), (g, b));
+}
@@ -234,6 +234,13 @@ fn all_builtins_have_required_gradients() {
     }
 }
 
+#[test]
+fn names_all_matches_contract() {
+    assert_eq!(opaline::names::tokens::ALL, REQUIRED_TOKENS);
+    assert_eq!(opaline::names::styles::ALL, REQUIRED_STYLES);
+    assert_eq!(opaline::names::gradients::ALL, REQUIRED_GRADIENTS);
+}
+
 // ── Variant correctness ──────────────────────────────────────────────────
 
 #[test]
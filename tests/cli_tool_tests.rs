@@ -0,0 +1,100 @@
+#![cfg(feature = "bin")]
+
+use std::process::{Command, Output};
+
+fn opaline(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_opaline"))
+        .args(args)
+        .output()
+        .expect("runs the opaline binary")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("utf-8 output")
+}
+
+#[test]
+fn list_includes_builtins_with_variant() {
+    let output = opaline(&["list"]);
+    assert!(output.status.success());
+    let listing = stdout(&output);
+    assert!(
+        listing
+            .lines()
+            .any(|l| l.starts_with("nord ") && l.contains("Dark"))
+    );
+}
+
+#[test]
+fn new_scaffold_satisfies_contract() {
+    for variant in ["dark", "light"] {
+        let output = opaline(&["new", "Scaffold", "--variant", variant]);
+        assert!(output.status.success());
+
+        let theme = opaline::load_from_str(&stdout(&output), None).expect("scaffold loads");
+        assert_eq!(theme.meta.name, "Scaffold");
+        for name in opaline::names::tokens::ALL {
+            assert!(theme.has_token(name), "missing token {name}");
+        }
+        for name in opaline::names::styles::ALL {
+            assert!(theme.has_style(name), "missing style {name}");
+        }
+        for name in opaline::names::gradients::ALL {
+            assert!(theme.has_gradient(name), "missing gradient {name}");
+        }
+    }
+}
+
+#[test]
+fn new_refuses_to_overwrite_without_force() {
+    let path = std::env::temp_dir().join(format!("opaline-new-{}.toml", std::process::id()));
+    std::fs::write(&path, "keep me").expect("writes file");
+    let target = path.to_str().expect("utf-8 path");
+
+    let output = opaline(&["new", "Scaffold", "-o", target]);
+    assert!(!output.status.success());
+    assert_eq!(std::fs::read_to_string(&path).expect("reads"), "keep me");
+
+    let output = opaline(&["new", "Scaffold", "-o", target, "--force"]);
+    let written = std::fs::read_to_string(&path).expect("reads");
+    std::fs::remove_file(&path).ok();
+    assert!(output.status.success());
+    assert!(written.starts_with("[meta]"));
+}
+
+#[test]
+fn validate_reports_all_errors_and_fails() {
+    let path = std::env::temp_dir().join(format!("opaline-validate-{}.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        "[meta]\nname = \"Broken\"\n\n[tokens]\na = \"missing\"\nb = \"also_missing\"\n",
+    )
+    .expect("writes theme");
+
+    let output = opaline(&["validate", path.to_str().expect("utf-8 path")]);
+    std::fs::remove_file(&path).ok();
+
+    assert!(!output.status.success());
+    let report = stdout(&output);
+    assert!(report.contains("'missing'"));
+    assert!(report.contains("'also_missing'"));
+}
+
+#[test]
+fn export_toml_round_trips() {
+    let output = opaline(&["export", "nord", "--format", "toml"]);
+    assert!(output.status.success());
+
+    let exported = opaline::load_from_str(&stdout(&output), None).expect("export loads");
+    let nord = opaline::load_by_name("nord").expect("builtin");
+    assert_eq!(
+        exported.color("accent.primary"),
+        nord.color("accent.primary")
+    );
+}
+
+#[test]
+fn export_rejects_unknown_format() {
+    let output = opaline(&["export", "nord", "--format", "pdf"]);
+    assert!(!output.status.success());
+}
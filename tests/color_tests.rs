@@ -123,3 +123,65 @@ fn lerp_clamps_above_one() {
     let b = OpalineColor::new(200, 200, 200);
     assert_eq!(a.lerp(b, 2.0), b);
 }
+
+#[test]
+fn contrast_ratio_black_on_white() {
+    let ratio = OpalineColor::BLACK.contrast_ratio(OpalineColor::WHITE);
+    assert!((ratio - 21.0).abs() < 0.01);
+    assert!((OpalineColor::WHITE.contrast_ratio(OpalineColor::BLACK) - ratio).abs() < f32::EPSILON);
+}
+
+#[test]
+fn contrast_ratio_matches_wcag_reference() {
+    // #767676 on white is the classic 4.54:1 AA threshold example.
+    let gray = OpalineColor::new(118, 118, 118);
+    let ratio = gray.contrast_ratio(OpalineColor::WHITE);
+    assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+    assert!((gray.contrast_ratio(gray) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn hsl_of_primaries() {
+    assert_eq!(OpalineColor::new(255, 0, 0).to_hsl(), (0.0, 1.0, 0.5));
+    assert_eq!(OpalineColor::new(0, 255, 0).to_hsl(), (120.0, 1.0, 0.5));
+    assert_eq!(OpalineColor::new(0, 0, 255).to_hsl(), (240.0, 1.0, 0.5));
+    assert_eq!(OpalineColor::WHITE.to_hsl(), (0.0, 0.0, 1.0));
+}
+
+#[test]
+fn hsl_round_trips() {
+    for color in [
+        OpalineColor::new(225, 53, 255),
+        OpalineColor::new(128, 255, 234),
+        OpalineColor::new(18, 18, 24),
+        OpalineColor::new(250, 250, 250),
+    ] {
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(OpalineColor::from_hsl(h, s, l), color);
+    }
+    assert_eq!(
+        OpalineColor::from_hsl(-120.0, 1.0, 0.5),
+        OpalineColor::new(0, 0, 255)
+    );
+}
+
+#[test]
+fn oklab_of_extremes() {
+    let (l, a, b) = OpalineColor::WHITE.to_oklab();
+    assert!((l - 1.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+    let (l, _, _) = OpalineColor::BLACK.to_oklab();
+    assert!(l.abs() < 1e-3);
+}
+
+#[test]
+fn oklab_round_trips() {
+    for color in [
+        OpalineColor::new(225, 53, 255),
+        OpalineColor::new(128, 255, 234),
+        OpalineColor::new(18, 18, 24),
+        OpalineColor::new(250, 250, 250),
+    ] {
+        let (l, a, b) = color.to_oklab();
+        assert_eq!(OpalineColor::from_oklab(l, a, b), color);
+    }
+}
@@ -0,0 +1,187 @@
+use opaline::names::{gradients, styles, tokens};
+use opaline::{Contract, OpalineColor, Theme};
+
+#[test]
+fn standard_contract_lists_names() {
+    let contract = Contract::standard();
+    assert_eq!(contract.tokens().len(), tokens::ALL.len());
+    assert_eq!(contract.styles().len(), styles::ALL.len());
+    assert_eq!(contract.gradients().len(), gradients::ALL.len());
+    assert_eq!(Contract::default(), contract);
+}
+
+#[test]
+fn require_token_skips_duplicates() {
+    let contract = Contract::empty()
+        .require_token("git.added")
+        .require_tokens(["git.added", "git.removed"]);
+    assert_eq!(contract.tokens(), ["git.added", "git.removed"]);
+}
+
+#[test]
+fn report_lists_missing_and_extra() {
+    let theme = Theme::builder("Partial")
+        .token(tokens::TEXT_PRIMARY, OpalineColor::new(255, 255, 255))
+        .token("app.badge", OpalineColor::new(1, 2, 3))
+        .build();
+
+    let report = Contract::empty()
+        .require_tokens([tokens::TEXT_PRIMARY, tokens::TEXT_MUTED])
+        .require_style(styles::KEYWORD)
+        .check(&theme);
+
+    assert!(!report.is_satisfied());
+    assert_eq!(report.missing_tokens, [tokens::TEXT_MUTED]);
+    assert_eq!(report.missing_styles, [styles::KEYWORD]);
+    assert_eq!(report.extra_tokens, ["app.badge"]);
+    assert_eq!(report.missing(), ["token 'text.muted'", "style 'keyword'"]);
+}
+
+#[test]
+fn empty_contract_is_always_satisfied() {
+    let report = Contract::empty().check(&Theme::builder("Empty").build());
+    assert!(report.is_satisfied());
+    assert_eq!(report.to_string(), "contract satisfied");
+}
+
+#[cfg(feature = "builtin-themes")]
+#[test]
+fn builtins_satisfy_standard_contract() {
+    for &(id, _) in opaline::builtins::builtin_names() {
+        let theme = opaline::load_by_name(id).expect("loads");
+        let report = theme.validate_contract();
+        assert!(report.is_satisfied(), "theme '{id}': {report}");
+    }
+}
+
+#[test]
+fn check_file_works_before_resolving() {
+    let theme_file: opaline::ThemeFile = toml::from_str(
+        r#"
+[meta]
+name = "Unresolved"
+
+[tokens]
+"text.primary" = "nowhere"
+"#,
+    )
+    .expect("parses");
+
+    let report = Contract::empty()
+        .require_tokens([tokens::TEXT_PRIMARY, tokens::BG_BASE])
+        .check_file(&theme_file);
+    assert_eq!(report.missing_tokens, [tokens::BG_BASE]);
+}
+
+#[test]
+fn palette_entries_satisfy_required_tokens() {
+    let theme = Theme::builder("Palette")
+        .palette(tokens::SUCCESS, OpalineColor::new(0, 255, 0))
+        .build();
+
+    let report = Contract::empty()
+        .require_token(tokens::SUCCESS)
+        .check(&theme);
+    assert!(report.is_satisfied());
+    assert_eq!(report.extra_tokens.len(), 0);
+}
+
+#[test]
+fn check_file_accepts_palette_entries_for_required_tokens() {
+    let theme_file: opaline::ThemeFile = toml::from_str(
+        r##"
+[meta]
+name = "Palette Only"
+
+[palette]
+success = "#00ff00"
+"##,
+    )
+    .expect("parses");
+
+    let report = Contract::empty()
+        .require_tokens([tokens::SUCCESS, tokens::ERROR])
+        .check_file(&theme_file);
+    assert_eq!(report.missing_tokens, [tokens::ERROR]);
+    assert_eq!(report.extra_tokens.len(), 0);
+}
+
+// ── fill_missing ─────────────────────────────────────────────────────────
+
+#[test]
+fn fill_missing_completes_an_empty_theme() {
+    let mut theme = Theme::builder("Empty").build();
+
+    let before = theme.fill_missing();
+    assert_eq!(before.missing_tokens.len(), tokens::ALL.len());
+
+    let after = theme.validate_contract();
+    assert!(after.is_satisfied(), "{after}");
+}
+
+#[test]
+fn fill_missing_keeps_defined_values() {
+    let accent = OpalineColor::new(10, 20, 30);
+    let mut theme = Theme::builder("Partial")
+        .token(tokens::ACCENT_PRIMARY, accent)
+        .build();
+
+    theme.fill_missing();
+
+    assert_eq!(theme.color(tokens::ACCENT_PRIMARY), accent);
+    assert_eq!(theme.color(tokens::BORDER_FOCUSED), accent);
+    assert_eq!(theme.color(tokens::CODE_KEYWORD), accent);
+    assert_eq!(theme.style(styles::KEYWORD).fg, Some(accent));
+}
+
+#[test]
+fn fill_missing_is_variant_aware() {
+    let base = OpalineColor::new(128, 128, 128);
+    let mut dark = Theme::builder("Dark").token(tokens::BG_BASE, base).build();
+    let mut light = Theme::builder("Light")
+        .variant(opaline::ThemeVariant::Light)
+        .token(tokens::BG_BASE, base)
+        .build();
+
+    dark.fill_missing();
+    light.fill_missing();
+
+    assert_eq!(dark.color(tokens::BG_SELECTION), base.lighten(0.15));
+    assert_eq!(light.color(tokens::BG_SELECTION), base.darken(0.15));
+    assert!(dark.color(tokens::TEXT_PRIMARY).relative_luminance() > base.relative_luminance());
+    assert!(light.color(tokens::TEXT_PRIMARY).relative_luminance() < base.relative_luminance());
+}
+
+#[test]
+fn fill_missing_derives_text_shades_from_text_and_background() {
+    let text = OpalineColor::new(240, 240, 240);
+    let bg = OpalineColor::new(16, 16, 16);
+    let mut theme = Theme::builder("Shades")
+        .token(tokens::TEXT_PRIMARY, text)
+        .token(tokens::BG_BASE, bg)
+        .build();
+
+    theme.fill_missing();
+
+    assert_eq!(theme.color(tokens::TEXT_MUTED), text.lerp(bg, 0.45));
+    assert_eq!(
+        theme.color(tokens::CODE_LINE_NUMBER),
+        theme.color(tokens::TEXT_DIM)
+    );
+}
+
+#[cfg(feature = "gradients")]
+#[test]
+fn fill_missing_builds_gradients_from_tokens() {
+    let warning = OpalineColor::new(255, 200, 0);
+    let error = OpalineColor::new(255, 0, 0);
+    let mut theme = Theme::builder("Status")
+        .token(tokens::WARNING, warning)
+        .token(tokens::ERROR, error)
+        .build();
+
+    theme.fill_missing();
+
+    let warm = theme.get_gradient(gradients::WARM).expect("derived");
+    assert_eq!(warm.stops(), [warning, error]);
+}
@@ -183,4 +183,23 @@ mod gradient_tests {
         let bar = opaline::adapters::crossterm::gradient_bar(0, '█', &gradient);
         assert!(bar.is_empty());
     }
+
+    #[test]
+    fn gradient_styled_at_phase_scrolls() {
+        let gradient = Gradient::new(vec![
+            OpalineColor::new(255, 0, 0),
+            OpalineColor::new(0, 0, 255),
+        ]);
+
+        let still = opaline::adapters::crossterm::gradient_styled_at_phase("abcd", &gradient, 0.0);
+        let moved = opaline::adapters::crossterm::gradient_styled_at_phase("abcd", &gradient, 0.25);
+        assert_eq!(moved.len(), 4);
+        assert_eq!(
+            moved[1].style().foreground_color,
+            still[0].style().foreground_color
+        );
+
+        let bar = opaline::adapters::crossterm::gradient_bar_at_phase(3, '█', &gradient, 2.5);
+        assert_eq!(bar.len(), 3);
+    }
 }
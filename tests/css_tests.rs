@@ -1,5 +1,9 @@
 #![cfg(feature = "css")]
 
+use opaline::adapters::css::{
+    CssOptions, generate_css_classes_with, generate_less_maps, generate_scss_maps,
+    generate_stylesheet_with,
+};
 use opaline::{OpalineColor, OpalineStyle, Theme};
 
 #[test]
@@ -110,3 +114,147 @@ fn css_decorations_combined() {
     let css = opaline::generate_css_classes(&theme);
     assert!(css.contains("text-decoration: underline line-through;"));
 }
+
+fn scheme_pair() -> (Theme, Theme) {
+    let dark = Theme::builder("Dark")
+        .token("bg.base", OpalineColor::new(18, 18, 24))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build();
+    let light = Theme::builder("Light")
+        .token("bg.base", OpalineColor::new(250, 250, 250))
+        .style("keyword", OpalineStyle::fg(OpalineColor::new(120, 0, 160)))
+        .style(
+            "selected",
+            OpalineStyle::new().with_bg(OpalineColor::new(221, 221, 221)),
+        )
+        .build();
+    (dark, light)
+}
+
+#[test]
+fn css_pair_emits_media_and_data_theme_blocks() {
+    let (dark, light) = scheme_pair();
+    let css = opaline::generate_stylesheet_pair(&dark, &light);
+
+    assert!(css.contains(
+        "@media (prefers-color-scheme: dark) {\n  :root {\n    --opaline-bg-base: #121218;"
+    ));
+    assert!(css.contains(
+        "@media (prefers-color-scheme: light) {\n  :root {\n    --opaline-bg-base: #fafafa;"
+    ));
+    assert!(css.contains("[data-theme=\"dark\"] {\n  --opaline-bg-base: #121218;"));
+    assert!(css.contains("[data-theme=\"light\"] {\n  --opaline-bg-base: #fafafa;"));
+    assert!(css.contains("--opaline-style-keyword-fg: #e135ff;"));
+    assert!(css.contains("--opaline-style-keyword-fg: #7800a0;"));
+}
+
+#[test]
+fn css_pair_classes_reference_style_vars() {
+    let (dark, light) = scheme_pair();
+    let css = opaline::generate_stylesheet_pair(&dark, &light);
+
+    assert!(css.contains(
+        ".opaline-keyword {\n  color: var(--opaline-style-keyword-fg);\n  font-weight: bold;\n}"
+    ));
+    assert!(
+        css.contains(
+            ".opaline-selected {\n  background-color: var(--opaline-style-selected-bg);\n}"
+        )
+    );
+    assert_eq!(css.matches(".opaline-keyword {").count(), 1);
+}
+
+#[test]
+fn css_options_prefixes_and_selector() {
+    let theme = Theme::builder("Test")
+        .token("accent.primary", OpalineColor::new(225, 53, 255))
+        .style("keyword", OpalineStyle::fg(OpalineColor::new(225, 53, 255)))
+        .build();
+    let options = CssOptions::new()
+        .var_prefix("app")
+        .root_selector(".themed")
+        .class_prefix("");
+
+    let css = generate_stylesheet_with(&theme, &options);
+    assert!(css.starts_with(".themed {\n  --app-accent-primary: #e135ff;\n}"));
+    assert!(css.contains(".keyword {\n  color: #e135ff;\n}"));
+}
+
+#[test]
+fn css_options_use_vars_links_token_colors() {
+    let theme = opaline::load_from_str(
+        r##"
+[meta]
+name = "Vars"
+
+[palette]
+purple = "#e135ff"
+
+[tokens]
+"accent.primary" = "purple"
+
+[styles]
+keyword = { fg = "accent.primary", bg = "purple", bold = true }
+"##,
+        None,
+    )
+    .expect("theme loads");
+
+    let css = generate_css_classes_with(&theme, &CssOptions::new().use_vars(true));
+    assert!(css.contains("color: var(--opaline-accent-primary);"));
+    assert!(css.contains("background-color: #e135ff;"));
+}
+
+#[test]
+fn css_options_minify() {
+    let theme = Theme::builder("Test")
+        .token("bg.base", OpalineColor::new(18, 18, 24))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build();
+
+    let css = generate_stylesheet_with(&theme, &CssOptions::new().minify(true));
+    assert_eq!(
+        css,
+        ":root{--opaline-bg-base:#121218;}.opaline-keyword{color:#e135ff;font-weight:bold;}"
+    );
+}
+
+#[test]
+fn scss_maps() {
+    let theme = Theme::builder("Test")
+        .token("accent.primary", OpalineColor::new(225, 53, 255))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build();
+
+    let scss = generate_scss_maps(&theme, "opaline");
+    assert!(scss.contains("$opaline-tokens: (\n  \"accent-primary\": #e135ff,\n);"));
+    assert!(scss.contains(
+        "$opaline-styles: (\n  \"keyword\": (\"color\": #e135ff, \"font-weight\": bold),\n);"
+    ));
+}
+
+#[test]
+fn less_maps() {
+    let theme = Theme::builder("Test")
+        .token("accent.primary", OpalineColor::new(225, 53, 255))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build();
+
+    let less = generate_less_maps(&theme, "app");
+    assert!(less.contains("@app-tokens: {\n  accent-primary: #e135ff;\n}"));
+    assert!(less.contains(
+        "@app-styles: {\n  keyword {\n    color: #e135ff;\n    font-weight: bold;\n  }\n}"
+    ));
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "dtcg")]
+
+use opaline::adapters::dtcg::{
+    from_dtcg_str, load_from_dtcg_str, theme_to_dtcg, to_dtcg, to_dtcg_string,
+};
+use opaline::{OpalineColor, OpalineError, ThemeFile, ThemeVariant};
+use serde_json::json;
+
+const THEME_TOML: &str = r##"
+[meta]
+name = "DTCG Test"
+author = "tester"
+variant = "light"
+
+[palette]
+purple_500 = "#E135FF"
+cyan_400 = "#80ffea"
+
+[tokens]
+"accent.primary" = "purple_500"
+"accent.secondary" = "cyan_400"
+"text.primary" = "#f8f8f2"
+"border.focused" = "accent.primary"
+
+[styles]
+keyword = { fg = "accent.primary", bold = true }
+
+[gradients]
+primary = ["purple_500", "accent.secondary", "#ff6ac1"]
+
+[syntax]
+"keyword.control" = "accent.secondary"
+"##;
+
+fn theme_file() -> ThemeFile {
+    toml::from_str(THEME_TOML).expect("valid theme TOML")
+}
+
+#[test]
+fn palette_exports_as_primitive_colors() {
+    let doc = to_dtcg(&theme_file());
+    assert_eq!(
+        doc["palette"]["purple_500"],
+        json!({ "$type": "color", "$value": "#e135ff" })
+    );
+}
+
+#[test]
+fn tokens_export_as_nested_aliases() {
+    let doc = to_dtcg(&theme_file());
+    assert_eq!(
+        doc["tokens"]["accent"]["primary"]["$value"],
+        "{palette.purple_500}"
+    );
+    assert_eq!(
+        doc["tokens"]["border"]["focused"]["$value"],
+        "{tokens.accent.primary}"
+    );
+    assert_eq!(doc["tokens"]["text"]["primary"]["$value"], "#f8f8f2");
+}
+
+#[test]
+fn gradients_export_with_positions() {
+    let doc = to_dtcg(&theme_file());
+    let gradient = &doc["gradients"]["primary"];
+    assert_eq!(gradient["$type"], "gradient");
+    assert_eq!(
+        gradient["$value"],
+        json!([
+            { "color": "{palette.purple_500}", "position": 0.0 },
+            { "color": "{tokens.accent.secondary}", "position": 0.5 },
+            { "color": "#ff6ac1", "position": 1.0 },
+        ])
+    );
+}
+
+#[test]
+fn extension_carries_meta_styles_and_syntax() {
+    let doc = to_dtcg(&theme_file());
+    let ext = &doc["$extensions"]["dev.opaline"];
+    assert_eq!(ext["meta"]["name"], "DTCG Test");
+    assert_eq!(ext["styles"]["keyword"]["bold"], true);
+    assert_eq!(ext["syntax"]["keyword.control"], "accent.secondary");
+}
+
+#[test]
+fn round_trip_preserves_theme_file() {
+    let original = theme_file();
+    let imported = from_dtcg_str(&to_dtcg_string(&original)).expect("imports");
+
+    assert_eq!(imported.meta.name, "DTCG Test");
+    assert_eq!(imported.meta.variant, ThemeVariant::Light);
+    assert_eq!(imported.palette["purple_500"], "#e135ff");
+    assert_eq!(imported.tokens["accent.primary"], "purple_500");
+    assert_eq!(imported.tokens["border.focused"], "accent.primary");
+    assert_eq!(
+        imported.gradients["primary"],
+        vec!["purple_500", "accent.secondary", "#ff6ac1"]
+    );
+    assert!(imported.styles["keyword"].bold);
+    assert_eq!(imported.syntax["keyword.control"], "accent.secondary");
+}
+
+#[test]
+fn round_trip_resolves_to_same_colors() {
+    let original = opaline::load_from_str(THEME_TOML, None).expect("loads");
+    let imported = load_from_dtcg_str(&to_dtcg_string(&theme_file())).expect("imports");
+
+    for name in ["accent.primary", "accent.secondary", "border.focused"] {
+        assert_eq!(original.color(name), imported.color(name), "{name}");
+    }
+    assert_eq!(original.style("keyword"), imported.style("keyword"));
+}
+
+#[test]
+fn resolved_theme_exports_recorded_aliases() {
+    let theme = opaline::load_from_str(THEME_TOML, None).expect("loads");
+    let doc = theme_to_dtcg(&theme);
+    assert_eq!(
+        doc["tokens"]["border"]["focused"]["$value"],
+        "{tokens.accent.primary}"
+    );
+    assert_eq!(doc["tokens"]["text"]["primary"]["$value"], "#f8f8f2");
+    assert_eq!(
+        doc["$extensions"]["dev.opaline"]["styles"]["keyword"]["fg"],
+        "accent.primary"
+    );
+    assert_eq!(
+        doc["gradients"]["primary"]["$value"][1]["color"],
+        "{tokens.accent.secondary}"
+    );
+}
+
+#[test]
+fn built_theme_exports_literal_values() {
+    let theme = opaline::Theme::builder("Built")
+        .token("accent.primary", OpalineColor::new(225, 53, 255))
+        .build();
+    let doc = theme_to_dtcg(&theme);
+    assert_eq!(doc["tokens"]["accent"]["primary"]["$value"], "#e135ff");
+}
+
+#[test]
+fn imports_foreign_token_layout() {
+    let json = r##"{
+        "color": {
+            "$type": "color",
+            "blue": { "500": { "$value": "#3b82f6" } },
+            "brand": { "$value": "{color.blue.500}" }
+        },
+        "spacing": { "sm": { "$type": "dimension", "$value": "4px" } }
+    }"##;
+
+    let tf = from_dtcg_str(json).expect("imports");
+    assert_eq!(tf.meta.name, "Imported");
+    assert_eq!(tf.palette["color.blue.500"], "#3b82f6");
+    assert_eq!(tf.tokens["color.brand"], "color.blue.500");
+    assert!(!tf.palette.contains_key("spacing.sm"));
+
+    let theme = opaline::adapters::dtcg::load_from_dtcg_str(json).expect("resolves");
+    assert_eq!(theme.color("color.brand"), OpalineColor::new(59, 130, 246));
+}
+
+#[test]
+fn imports_color_object_and_short_hex() {
+    let json = r##"{
+        "palette": {
+            "a": { "$type": "color", "$value": { "colorSpace": "srgb", "components": [1, 0, 0.5] } },
+            "b": { "$type": "color", "$value": "#0f08" }
+        }
+    }"##;
+
+    let tf = from_dtcg_str(json).expect("imports");
+    assert_eq!(tf.palette["a"], "#ff0080");
+    assert_eq!(tf.palette["b"], "#00ff00");
+}
+
+#[test]
+fn non_ascii_hex_is_rejected_without_panicking() {
+    let json = r##"{ "palette": { "a": { "$type": "color", "$value": "#aaaaa\u00e9a" } } }"##;
+    assert!(matches!(
+        from_dtcg_str(json),
+        Err(OpalineError::InvalidFormat { .. })
+    ));
+}
+
+#[test]
+fn unknown_alias_errors() {
+    let json = r#"{ "tokens": { "x": { "$type": "color", "$value": "{palette.missing}" } } }"#;
+    assert!(matches!(
+        from_dtcg_str(json),
+        Err(OpalineError::UnresolvedToken { .. })
+    ));
+}
+
+#[test]
+fn invalid_json_errors() {
+    assert!(matches!(
+        from_dtcg_str("not json"),
+        Err(OpalineError::InvalidFormat { .. })
+    ));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "image")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use opaline::extract::{dominant_colors, palette_from_image};
+use opaline::{OpalineColor, OpalineError, ThemeBuilder, ThemeVariant};
+use pretty_assertions::assert_eq;
+
+const NAVY: OpalineColor = OpalineColor::new(0x10, 0x14, 0x2c);
+const ORANGE: OpalineColor = OpalineColor::new(0xf2, 0x8c, 0x28);
+const TEAL: OpalineColor = OpalineColor::new(0x1f, 0xb5, 0xa8);
+
+fn temp_dir(label: &str) -> PathBuf {
+    let unique = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("opaline-{label}-{unique}"));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    dir
+}
+
+/// A 60×40 wallpaper: navy sky over an orange band and a teal strip.
+fn write_wallpaper(path: &std::path::Path) {
+    let image = image::RgbImage::from_fn(60, 40, |_, y| {
+        let c = match y {
+            0..24 => NAVY,
+            24..34 => ORANGE,
+            _ => TEAL,
+        };
+        image::Rgb([c.r, c.g, c.b])
+    });
+    image.save(path).expect("write png");
+}
+
+#[test]
+fn dominant_colors_orders_by_population() {
+    let pixels = std::iter::repeat_n(NAVY, 600)
+        .chain(std::iter::repeat_n(ORANGE, 250))
+        .chain(std::iter::repeat_n(TEAL, 150));
+    assert_eq!(dominant_colors(pixels, 3), vec![NAVY, ORANGE, TEAL]);
+}
+
+#[test]
+fn dominant_colors_merges_similar_pixels() {
+    let pixels = [NAVY, OpalineColor::new(0x12, 0x15, 0x2d), ORANGE];
+    let colors = dominant_colors(pixels, 8);
+    assert_eq!(colors.len(), 2);
+}
+
+#[test]
+fn dominant_colors_of_nothing() {
+    assert_eq!(dominant_colors([], 8).len(), 0);
+}
+
+#[test]
+fn dominant_colors_with_zero_count() {
+    assert_eq!(dominant_colors([NAVY, ORANGE, TEAL], 0).len(), 0);
+}
+
+#[test]
+fn palette_from_png() {
+    let dir = temp_dir("extract");
+    let path = dir.join("wallpaper.png");
+    write_wallpaper(&path);
+
+    let colors = palette_from_image(&path, 3).expect("decode png");
+    assert_eq!(colors, vec![NAVY, ORANGE, TEAL]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn theme_from_image_is_complete() {
+    let dir = temp_dir("extract-theme");
+    let path = dir.join("sunset.png");
+    write_wallpaper(&path);
+
+    for variant in [ThemeVariant::Dark, ThemeVariant::Light] {
+        let theme = ThemeBuilder::from_image(&path, variant)
+            .expect("decode png")
+            .build();
+        assert_eq!(theme.meta.name, "sunset");
+        assert_eq!(theme.meta.variant, variant);
+        assert!(theme.validate_contract().is_satisfied());
+    }
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn undecodable_image_is_an_error() {
+    let dir = temp_dir("extract-bad");
+    let path = dir.join("notes.png");
+    fs::write(&path, "not an image").expect("write file");
+
+    let err = palette_from_image(&path, 8).expect_err("not an image");
+    assert!(matches!(err, OpalineError::Image { .. }));
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,123 @@
+#![cfg(all(feature = "widgets", feature = "gradients"))]
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier};
+use ratatui::widgets::{Block, Borders, Padding, Widget};
+
+use opaline::names::{gradients, styles};
+use opaline::{Gradient, GradientBlock, OpalineColor, OpalineStyle, Theme};
+
+const PURPLE: OpalineColor = OpalineColor::new(0xe1, 0x35, 0xff);
+const CYAN: OpalineColor = OpalineColor::new(0x80, 0xff, 0xea);
+const FOCUSED: OpalineColor = OpalineColor::new(0xff, 0x6a, 0xc1);
+const UNFOCUSED: OpalineColor = OpalineColor::new(0x44, 0x47, 0x5a);
+
+fn theme() -> Theme {
+    Theme::builder("Blocks")
+        .style(styles::FOCUSED_BORDER, OpalineStyle::fg(FOCUSED).bold())
+        .style(styles::UNFOCUSED_BORDER, OpalineStyle::fg(UNFOCUSED))
+        .gradient(gradients::PRIMARY, Gradient::new(vec![PURPLE, CYAN]))
+        .build()
+}
+
+fn render(block: &GradientBlock<'_>, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    block.render(area, &mut buf);
+    buf
+}
+
+fn fg(buf: &Buffer, x: u16, y: u16) -> Color {
+    buf[(x, y)].fg
+}
+
+#[test]
+fn gradient_runs_clockwise_from_top_left() {
+    let theme = theme();
+    let buf = render(&GradientBlock::new(&theme), 4, 3);
+
+    // Perimeter of a 4×3 block: 10 cells, the last one at (0, 1).
+    assert_eq!(fg(&buf, 0, 0), Color::from(PURPLE));
+    assert_eq!(fg(&buf, 0, 1), Color::from(CYAN));
+    assert_eq!(
+        fg(&buf, 3, 0),
+        Color::from(theme.gradient("primary", 3.0 / 9.0))
+    );
+    assert_eq!(
+        fg(&buf, 3, 2),
+        Color::from(theme.gradient("primary", 5.0 / 9.0))
+    );
+    assert_eq!(
+        fg(&buf, 0, 2),
+        Color::from(theme.gradient("primary", 8.0 / 9.0))
+    );
+    assert_eq!(buf[(0, 0)].symbol(), "┌");
+}
+
+#[test]
+fn focused_border_style_modifiers_are_kept() {
+    let theme = theme();
+    let buf = render(&GradientBlock::new(&theme), 4, 3);
+    assert!(buf[(1, 0)].modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn titles_take_the_gradient() {
+    let theme = theme();
+    let block = GradientBlock::new(&theme).block(Block::new().title("Hi"));
+    let buf = render(&block, 6, 3);
+
+    assert_eq!(buf[(1, 0)].symbol(), "H");
+    assert_eq!(
+        fg(&buf, 1, 0),
+        Color::from(theme.gradient("primary", 1.0 / 13.0))
+    );
+}
+
+#[test]
+fn unfocused_blocks_are_flat() {
+    let theme = theme();
+    let buf = render(&GradientBlock::new(&theme).focused(false), 4, 3);
+    for (x, y) in [(0, 0), (3, 0), (3, 2), (0, 1)] {
+        assert_eq!(fg(&buf, x, y), Color::from(UNFOCUSED), "({x}, {y})");
+    }
+}
+
+#[test]
+fn missing_gradient_falls_back_to_focused_style() {
+    let theme = theme();
+    let buf = render(&GradientBlock::new(&theme).gradient("nonexistent"), 4, 3);
+    assert_eq!(fg(&buf, 0, 0), Color::from(FOCUSED));
+    assert_eq!(fg(&buf, 0, 1), Color::from(FOCUSED));
+}
+
+#[test]
+fn only_drawn_sides_are_painted() {
+    let theme = theme();
+    let block = GradientBlock::new(&theme).borders(Borders::TOP | Borders::BOTTOM);
+    let buf = render(&block, 4, 3);
+
+    assert_eq!(fg(&buf, 0, 0), Color::from(PURPLE));
+    assert_eq!(buf[(0, 1)].symbol(), " ");
+    assert_eq!(fg(&buf, 0, 1), Color::Reset);
+}
+
+#[test]
+fn inner_accounts_for_borders_and_padding() {
+    let theme = theme();
+    let area = Rect::new(0, 0, 10, 6);
+    let block = GradientBlock::new(&theme).block(Block::new().padding(Padding::horizontal(1)));
+    assert_eq!(block.inner(area), Rect::new(2, 1, 6, 4));
+
+    let block = block.borders(Borders::LEFT);
+    assert_eq!(block.inner(area), Rect::new(2, 0, 7, 6));
+}
+
+#[test]
+fn tiny_areas_render() {
+    let theme = theme();
+    for (width, height) in [(0, 0), (1, 1), (1, 4), (5, 1)] {
+        render(&GradientBlock::new(&theme), width, height);
+    }
+}
@@ -1,4 +1,4 @@
-use opaline::{Gradient, OpalineColor};
+use opaline::{Gradient, GradientWrap, OpalineColor};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -125,3 +125,67 @@ fn empty_stops_panics() {
 fn empty_gradient_deserialization_fails() {
     assert!(serde_json::from_str::<Gradient>(r#"{"stops":[]}"#).is_err());
 }
+
+const RED: OpalineColor = OpalineColor::new(255, 0, 0);
+const BLUE: OpalineColor = OpalineColor::new(0, 0, 255);
+const PURPLE: OpalineColor = OpalineColor::new(128, 0, 128);
+
+#[test]
+fn sample_repeat_loops_without_a_seam() {
+    let g = Gradient::new(vec![RED, BLUE]);
+    assert_eq!(g.sample(0.0, GradientWrap::Repeat), RED);
+    assert_eq!(g.sample(0.25, GradientWrap::Repeat), PURPLE);
+    assert_eq!(g.sample(0.5, GradientWrap::Repeat), BLUE);
+    assert_eq!(g.sample(0.75, GradientWrap::Repeat), PURPLE);
+    assert_eq!(g.sample(1.0, GradientWrap::Repeat), RED);
+    assert_eq!(g.sample(-0.5, GradientWrap::Repeat), BLUE);
+    assert_eq!(g.sample(3.25, GradientWrap::Repeat), PURPLE);
+}
+
+#[test]
+fn sample_ping_pong_reflects() {
+    let g = Gradient::new(vec![RED, BLUE]);
+    assert_eq!(g.sample(0.0, GradientWrap::PingPong), RED);
+    assert_eq!(g.sample(1.0, GradientWrap::PingPong), BLUE);
+    assert_eq!(g.sample(1.5, GradientWrap::PingPong), g.at(0.5));
+    assert_eq!(g.sample(2.0, GradientWrap::PingPong), RED);
+    assert_eq!(g.sample(-0.25, GradientWrap::PingPong), g.at(0.25));
+}
+
+#[test]
+fn sample_clamp_matches_at() {
+    let g = Gradient::new(vec![RED, BLUE]);
+    for t in [-1.0, 0.0, 0.3, 1.0, 2.0] {
+        assert_eq!(g.sample(t, GradientWrap::Clamp), g.at(t));
+    }
+    assert_eq!(
+        Gradient::new(vec![RED]).sample(0.7, GradientWrap::Repeat),
+        RED
+    );
+}
+
+#[test]
+fn generate_at_phase_scrolls_forward() {
+    let g = Gradient::new(vec![RED, BLUE]);
+    assert_eq!(
+        g.generate_at_phase(4, 0.0, GradientWrap::Repeat),
+        vec![RED, PURPLE, BLUE, PURPLE]
+    );
+    assert_eq!(
+        g.generate_at_phase(4, 0.25, GradientWrap::Repeat),
+        vec![PURPLE, RED, PURPLE, BLUE]
+    );
+    assert_eq!(
+        g.generate_at_phase(4, 1.0, GradientWrap::Repeat),
+        g.generate_at_phase(4, 0.0, GradientWrap::Repeat)
+    );
+    assert_eq!(
+        g.generate_at_phase(5, 0.0, GradientWrap::Clamp),
+        g.generate(5)
+    );
+    assert_eq!(
+        g.generate_at_phase(1, 0.0, GradientWrap::PingPong),
+        g.generate(1)
+    );
+    assert_eq!(g.generate_at_phase(0, 0.5, GradientWrap::Repeat), vec![]);
+}
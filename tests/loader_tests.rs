@@ -193,3 +193,47 @@ keyword = { fg = "accent.primary", bold = true, unexpected = true }
     let err = loader::load_from_str(toml, None).expect_err("should fail");
     assert!(matches!(err, OpalineError::Parse { .. }));
 }
+
+#[test]
+fn strict_load_rejects_missing_contract_names() {
+    let options = loader::LoadOptions::new().strict();
+    let err = loader::load_from_str_with(MINIMAL_TOML, None, &options).expect_err("incomplete");
+
+    let OpalineError::ContractViolation { theme, report } = err else {
+        panic!("expected ContractViolation, got {err:?}");
+    };
+    assert_eq!(theme, "Minimal");
+    assert!(report.missing_tokens.contains(&"text.primary".to_string()));
+    assert!(
+        !report
+            .missing_tokens
+            .contains(&"accent.primary".to_string())
+    );
+}
+
+#[test]
+fn load_with_custom_contract() {
+    let satisfied = opaline::Contract::empty()
+        .require_tokens(["accent.primary", "accent.secondary"])
+        .require_style("keyword");
+    let options = loader::LoadOptions::new().contract(satisfied);
+    assert!(loader::load_from_str_with(MINIMAL_TOML, None, &options).is_ok());
+
+    let options =
+        loader::LoadOptions::new().contract(opaline::Contract::empty().require_token("git.added"));
+    let err = loader::load_from_str_with(MINIMAL_TOML, None, &options).expect_err("missing");
+    assert_eq!(
+        err.to_string(),
+        "theme 'Minimal' does not satisfy its contract: missing token 'git.added'"
+    );
+}
+
+#[test]
+fn fill_missing_satisfies_strict_load() {
+    let options = loader::LoadOptions::new().fill_missing().strict();
+    let theme = loader::load_from_str_with(MINIMAL_TOML, None, &options).expect("filled");
+
+    assert_eq!(theme.color("accent.primary"), OpalineColor::new(255, 0, 0));
+    assert!(theme.has_token("text.muted"));
+    assert!(theme.has_style("inline_code"));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "preview")]
+
+use opaline::adapters::preview::{render_html, render_svg};
+use opaline::{OpalineColor, OpalineStyle, Theme};
+
+fn preview_theme() -> Theme {
+    Theme::builder("Preview <Test>")
+        .author("tester")
+        .palette("purple_500", OpalineColor::new(225, 53, 255))
+        .token("bg.base", OpalineColor::new(18, 18, 24))
+        .token("text.primary", OpalineColor::new(255, 255, 255))
+        .token("code.keyword", OpalineColor::new(225, 53, 255))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build()
+}
+
+#[test]
+fn html_is_self_contained() {
+    let html = render_html(&preview_theme());
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Preview &lt;Test&gt; — Opaline preview</title>"));
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("<link"));
+    assert!(html.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn html_lists_palette_tokens_and_styles() {
+    let html = render_html(&preview_theme());
+    assert!(html.contains("<code>purple_500</code><code>#e135ff</code>"));
+    // White on #121218 is well above the AAA threshold.
+    assert!(html.contains(
+        "<td><code>text.primary</code></td><td><code>#ffffff</code></td><td>18.66:1 AAA</td>"
+    ));
+    assert!(html.contains("<span style=\"color: #e135ff; font-weight: bold\">keyword</span>"));
+    assert!(html.contains("<span style=\"color: #e135ff\">fn</span>"));
+}
+
+#[test]
+fn svg_card_renders_sections() {
+    let svg = render_svg(&preview_theme());
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("fill=\"#e135ff\" stroke="));
+    assert!(svg.contains(">keyword</text>"));
+    assert!(svg.contains("<tspan fill=\"#e135ff\">fn</tspan>"));
+    assert!(svg.contains("Preview &lt;Test&gt;"));
+}
+
+#[cfg(all(feature = "gradients", feature = "builtin-themes"))]
+#[test]
+fn builtin_previews_include_gradients() {
+    let theme = Theme::default();
+    let html = render_html(&theme);
+    assert!(html.contains("<h2>Gradients</h2>"));
+    assert!(html.contains("linear-gradient(to right,"));
+
+    let svg = render_svg(&theme);
+    assert!(svg.contains("<linearGradient id=\"gradient-0\">"));
+    assert!(svg.contains("fill=\"url(#gradient-0)\""));
+}
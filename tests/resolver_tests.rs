@@ -17,6 +17,7 @@ fn minimal_theme_file() -> ThemeFile {
         tokens: HashMap::new(),
         styles: HashMap::new(),
         gradients: HashMap::new(),
+        syntax: HashMap::new(),
     }
 }
 
@@ -205,3 +206,145 @@ fn gradient_with_unresolvable_stops_returns_error() {
     let err = opaline::resolver::resolve(&tf).expect_err("should fail");
     assert!(matches!(err, OpalineError::UnresolvedToken { .. }));
 }
+
+#[test]
+fn validate_accepts_valid_file() {
+    let mut tf = minimal_theme_file();
+    tf.palette.insert("red".to_string(), "#ff0000".to_string());
+    tf.tokens.insert("error".to_string(), "red".to_string());
+
+    assert_eq!(opaline::resolver::validate(&tf).len(), 0);
+}
+
+#[test]
+fn validate_reports_every_error() {
+    let mut tf = minimal_theme_file();
+    tf.palette.insert("bad".to_string(), "#zzzzzz".to_string());
+    tf.tokens.insert("a".to_string(), "missing".to_string());
+    tf.tokens.insert("b".to_string(), "c".to_string());
+    tf.tokens.insert("c".to_string(), "b".to_string());
+    tf.styles.insert(
+        "keyword".to_string(),
+        StyleDef {
+            fg: Some("nowhere".to_string()),
+            ..StyleDef::default()
+        },
+    );
+
+    let errors = opaline::resolver::validate(&tf);
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(errors[0], OpalineError::InvalidColor { .. }));
+    assert!(matches!(errors[1], OpalineError::UnresolvedToken { .. }));
+    assert!(matches!(errors[2], OpalineError::CircularReference { .. }));
+    assert!(matches!(errors[3], OpalineError::UnresolvedToken { .. }));
+}
+
+#[test]
+fn validate_names_each_empty_gradient() {
+    let mut tf = minimal_theme_file();
+    tf.gradients.insert("aurora".to_string(), vec![]);
+    tf.gradients.insert("ember".to_string(), vec![]);
+
+    let errors = opaline::resolver::validate(&tf);
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "invalid gradient 'aurora': gradient must have at least one color stop",
+            "invalid gradient 'ember': gradient must have at least one color stop",
+        ]
+    );
+    assert!(matches!(
+        &errors[0],
+        OpalineError::InvalidGradient { source, .. }
+            if matches!(**source, OpalineError::EmptyGradient)
+    ));
+}
+
+#[test]
+fn validate_does_not_cascade_from_bad_entries() {
+    let mut tf = minimal_theme_file();
+    tf.palette.insert("bad".to_string(), "#zzzzzz".to_string());
+    tf.tokens.insert("a".to_string(), "bad".to_string());
+    tf.tokens.insert("b".to_string(), "a".to_string());
+    tf.styles.insert(
+        "keyword".to_string(),
+        StyleDef {
+            fg: Some("b".to_string()),
+            ..StyleDef::default()
+        },
+    );
+
+    let errors = opaline::resolver::validate(&tf);
+    assert_eq!(errors.len(), 1);
+    assert!(opaline::resolver::resolve(&tf).is_err());
+}
+
+#[test]
+fn validate_agrees_with_resolve() {
+    let broken: Vec<fn(&mut ThemeFile)> = vec![
+        |tf| {
+            tf.palette.insert("bad".into(), "#zzzzzz".into());
+        },
+        |tf| {
+            tf.tokens.insert("a".into(), "missing".into());
+        },
+        |tf| {
+            tf.tokens.insert("a".into(), "b".into());
+            tf.tokens.insert("b".into(), "a".into());
+        },
+        |tf| {
+            tf.tokens.insert("a".into(), "#12".into());
+        },
+        |tf| {
+            tf.styles.insert(
+                "keyword".into(),
+                StyleDef {
+                    bg: Some("#nothex".into()),
+                    ..StyleDef::default()
+                },
+            );
+        },
+        |tf| {
+            tf.gradients.insert("empty".into(), vec![]);
+        },
+        |tf| {
+            tf.gradients
+                .insert("g".into(), vec!["#000000".into(), "nowhere".into()]);
+        },
+        |tf| {
+            tf.syntax.insert("keyword".into(), "nowhere".into());
+        },
+        |tf| {
+            tf.palette.insert("red".into(), "#ff0000".into());
+            tf.tokens.insert("error".into(), "red".into());
+            tf.styles.insert(
+                "error_style".into(),
+                StyleDef {
+                    fg: Some("error".into()),
+                    ..StyleDef::default()
+                },
+            );
+            tf.gradients
+                .insert("warm".into(), vec!["red".into(), "error".into()]);
+            tf.syntax.insert("keyword".into(), "error_style".into());
+        },
+    ];
+
+    for (i, edit) in broken.into_iter().enumerate() {
+        let mut tf = minimal_theme_file();
+        edit(&mut tf);
+
+        let resolved = opaline::resolver::resolve(&tf);
+        let errors = opaline::resolver::validate(&tf);
+        assert_eq!(resolved.is_ok(), errors.is_empty(), "fixture {i}");
+        // The first reported error is the one resolve stops at.
+        if let Err(err) = resolved {
+            assert!(
+                errors[0].to_string().contains(&err.to_string()),
+                "fixture {i}: {} vs {err}",
+                errors[0]
+            );
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use syntect::highlighting::{Color, FontStyle, StyleModifier};
 
+use opaline::adapters::syntect::{ScopeMapping, to_syntect_theme, to_syntect_theme_with};
 use opaline::{OpalineColor, OpalineStyle, Theme};
 
 #[test]
@@ -207,3 +208,221 @@ fn builtin_theme_converts_to_syntect() {
     assert!(st.settings.background.is_some());
     assert!(!st.scopes.is_empty());
 }
+
+#[test]
+fn scope_mapping_default_matches_builtin_table() {
+    let mapping = ScopeMapping::default();
+    assert_eq!(mapping.len(), 14);
+    assert_eq!(mapping.get("keyword"), Some("code.keyword"));
+    assert_eq!(mapping.get("markup.inserted"), Some("success"));
+}
+
+#[test]
+fn scope_mapping_insert_replaces_existing_scope() {
+    let mapping = ScopeMapping::default()
+        .with("keyword", "accent.secondary")
+        .with("markup.heading", "keyword");
+    assert_eq!(mapping.len(), 15);
+    assert_eq!(mapping.get("keyword"), Some("accent.secondary"));
+    assert_eq!(mapping.get("markup.heading"), Some("keyword"));
+}
+
+#[test]
+fn theme_syntax_table_merges_over_defaults() {
+    let theme = Theme::builder("Test")
+        .token("code.keyword", OpalineColor::new(203, 166, 247))
+        .token("accent.secondary", OpalineColor::new(128, 255, 234))
+        .syntax("keyword.control", "accent.secondary")
+        .build();
+
+    let mapping = ScopeMapping::for_theme(&theme);
+    assert_eq!(mapping.get("keyword"), Some("code.keyword"));
+    assert_eq!(mapping.get("keyword.control"), Some("accent.secondary"));
+
+    let st = to_syntect_theme(&theme);
+    let control = st
+        .scopes
+        .iter()
+        .find(|item| item.scope == "keyword.control".parse().expect("valid selector"))
+        .expect("should have keyword.control scope");
+    assert_eq!(
+        control.style.foreground,
+        Some(Color {
+            r: 128,
+            g: 255,
+            b: 234,
+            a: 255
+        })
+    );
+}
+
+#[test]
+fn scope_mapping_style_target() {
+    let theme = Theme::builder("Test")
+        .style(
+            "heading",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .build();
+
+    let mapping = ScopeMapping::empty().with("markup.heading", "heading");
+    let st = to_syntect_theme_with(&theme, &mapping);
+    assert_eq!(st.scopes.len(), 1);
+    let item = &st.scopes[0];
+    assert_eq!(
+        item.style.foreground,
+        Some(Color {
+            r: 225,
+            g: 53,
+            b: 255,
+            a: 255
+        })
+    );
+    assert!(
+        item.style
+            .font_style
+            .expect("font style")
+            .contains(FontStyle::BOLD)
+    );
+}
+
+#[test]
+fn scope_mapping_unknown_target_skipped() {
+    let theme = Theme::builder("Test").build();
+    let mapping = ScopeMapping::empty().with("keyword", "does.not.exist");
+    let st = to_syntect_theme_with(&theme, &mapping);
+    assert_eq!(st.scopes.len(), 0);
+}
+
+#[test]
+fn syntax_table_loads_from_toml() {
+    let theme = opaline::load_from_str(
+        r##"
+[meta]
+name = "Syntax"
+
+[tokens]
+"code.keyword" = "#cba6f7"
+"accent.secondary" = "#80ffea"
+
+[syntax]
+"keyword.control" = "accent.secondary"
+"##,
+        None,
+    )
+    .expect("theme loads");
+
+    assert_eq!(
+        theme.syntax_mapping().get("keyword.control"),
+        Some(&"accent.secondary".to_string())
+    );
+}
+
+#[test]
+fn syntax_table_unresolved_target_errors() {
+    let result = opaline::load_from_str(
+        r#"
+[meta]
+name = "Syntax"
+
+[syntax]
+"keyword.control" = "missing"
+"#,
+        None,
+    );
+    assert!(matches!(
+        result,
+        Err(opaline::OpalineError::UnresolvedToken { .. })
+    ));
+}
+
+#[cfg(feature = "syntect-highlight")]
+mod highlight {
+    use opaline::adapters::syntect::HighlightOptions;
+    use opaline::{OpalineColor, Theme};
+    use ratatui_core::style::Color;
+
+    fn code_theme() -> Theme {
+        Theme::builder("Code")
+            .token("text.primary", OpalineColor::new(205, 214, 244))
+            .token("bg.base", OpalineColor::new(30, 30, 46))
+            .token("bg.code", OpalineColor::new(24, 24, 37))
+            .token("code.keyword", OpalineColor::new(203, 166, 247))
+            .token("code.line_number", OpalineColor::new(108, 112, 134))
+            .build()
+    }
+
+    #[test]
+    fn highlight_code_produces_one_line_per_source_line() {
+        let text = code_theme().highlight_code("fn main() {\n}\n", "rs");
+        assert_eq!(text.lines.len(), 2);
+        let first: String = text.lines[0]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(first, "fn main() {");
+    }
+
+    #[test]
+    fn highlight_code_colors_keywords_with_code_bg() {
+        let text = code_theme().highlight_code("fn main() { if true {} }", "rs");
+        let keyword = text.lines[0]
+            .spans
+            .iter()
+            .find(|s| s.content == "if")
+            .expect("if span");
+        assert_eq!(keyword.style.fg, Some(Color::Rgb(203, 166, 247)));
+        assert_eq!(keyword.style.bg, Some(Color::Rgb(24, 24, 37)));
+        assert_eq!(text.style.bg, Some(Color::Rgb(24, 24, 37)));
+    }
+
+    #[test]
+    fn highlight_code_line_number_gutter() {
+        let code = "let x = 1;\n".repeat(10);
+        let options = HighlightOptions::new().line_numbers(true);
+        let text = code_theme().highlight_code_with(&code, "Rust", &options);
+        assert_eq!(text.lines.len(), 10);
+
+        let gutter = &text.lines[0].spans[0];
+        assert_eq!(gutter.content, " 1 ");
+        assert_eq!(gutter.style.fg, Some(Color::Rgb(108, 112, 134)));
+        assert_eq!(text.lines[9].spans[0].content, "10 ");
+    }
+
+    #[test]
+    fn highlight_code_unknown_syntax_is_plain_text() {
+        let text = code_theme().highlight_code("just words", "no-such-language");
+        let content: String = text.lines[0]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(content, "just words");
+    }
+
+    #[test]
+    fn highlight_code_without_plain_text_syntax_is_unhighlighted() {
+        let empty = syntect::parsing::SyntaxSetBuilder::new().build();
+        let options = HighlightOptions::new().syntax_set(&empty);
+        let text = code_theme().highlight_code_with("a\nb\n", "rs", &options);
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(text.lines[1].spans[0].content, "b");
+        assert_eq!(
+            text.lines[1].spans[0].style.bg,
+            Some(Color::Rgb(24, 24, 37))
+        );
+    }
+
+    #[test]
+    fn highlight_code_reuses_prebuilt_syntect_theme() {
+        let theme = code_theme();
+        let syntect_theme = opaline::adapters::syntect::to_syntect_theme(&theme);
+        let options = HighlightOptions::new().syntect_theme(&syntect_theme);
+        let code = "fn main() { if true {} }";
+        assert_eq!(
+            theme.highlight_code_with(code, "rs", &options),
+            theme.highlight_code(code, "rs")
+        );
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "tailwind")]
+
+use opaline::adapters::css::CssOptions;
+use opaline::adapters::tailwind::{
+    tailwind_colors, tailwind_colors_with, to_tailwind_config, to_tailwind_preset_js,
+    to_tailwind_theme_css,
+};
+use opaline::{OpalineColor, Theme};
+
+fn scale_theme() -> Theme {
+    Theme::builder("Scales")
+        .palette("purple_500", OpalineColor::new(225, 53, 255))
+        .palette("purple_50", OpalineColor::new(250, 240, 255))
+        .palette("purple", OpalineColor::new(200, 40, 230))
+        .palette("void", OpalineColor::new(10, 10, 16))
+        .token("accent.primary", OpalineColor::new(225, 53, 255))
+        .build()
+}
+
+#[test]
+fn palette_scales_group_by_name() {
+    let colors = tailwind_colors(&scale_theme());
+    assert_eq!(colors["purple"]["500"], "#e135ff");
+    assert_eq!(colors["purple"]["50"], "#faf0ff");
+    assert_eq!(colors["purple"]["DEFAULT"], "#c828e6");
+    assert_eq!(colors["void"], "#0a0a10");
+}
+
+#[test]
+fn tokens_reference_css_vars() {
+    let colors = tailwind_colors(&scale_theme());
+    assert_eq!(colors["accent-primary"], "var(--opaline-accent-primary)");
+
+    let colors = tailwind_colors_with(&scale_theme(), &CssOptions::new().var_prefix("app"));
+    assert_eq!(colors["accent-primary"], "var(--app-accent-primary)");
+}
+
+#[test]
+fn token_named_like_a_scale_becomes_its_default() {
+    let theme = Theme::builder("Collision")
+        .palette("success_500", OpalineColor::new(34, 197, 94))
+        .palette("success_600", OpalineColor::new(22, 163, 74))
+        .token("success", OpalineColor::new(34, 197, 94))
+        .build();
+
+    let colors = tailwind_colors(&theme);
+    assert_eq!(colors["success"]["500"], "#22c55e");
+    assert_eq!(colors["success"]["600"], "#16a34a");
+    assert_eq!(colors["success"]["DEFAULT"], "var(--opaline-success)");
+
+    let css = to_tailwind_theme_css(&theme);
+    assert!(css.contains("  --color-success: var(--opaline-success);"));
+    assert!(css.contains("  --color-success-600: #16a34a;"));
+}
+
+#[test]
+fn config_and_preset_wrap_colors() {
+    let config: serde_json::Value =
+        serde_json::from_str(&to_tailwind_config(&scale_theme())).expect("valid JSON");
+    assert_eq!(config["theme"]["extend"]["colors"]["void"], "#0a0a10");
+
+    let js = to_tailwind_preset_js(&scale_theme());
+    assert!(js.contains("module.exports = {"));
+    assert!(js.trim_end().ends_with("};"));
+}
+
+#[test]
+fn preset_header_cannot_inject_code() {
+    let theme = Theme::builder("Evil\nprocess.exit(1) */ require('x')").build();
+    let js = to_tailwind_preset_js(&theme);
+    let header = js.lines().next().expect("header line");
+    assert!(header.starts_with("/* Generated by opaline from "));
+    assert!(header.ends_with(" */"));
+    assert_eq!(header.matches("*/").count(), 1);
+    let body = js.lines().nth(1).expect("body line");
+    assert!(body.starts_with("module.exports"));
+}
+
+#[test]
+fn v4_theme_block() {
+    let css = to_tailwind_theme_css(&scale_theme());
+    assert!(css.starts_with("@theme inline {"));
+    assert!(css.contains(
+        "  --color-purple: #c828e6;\n  --color-purple-50: #faf0ff;\n  --color-purple-500: #e135ff;"
+    ));
+    assert!(css.contains("  --color-accent-primary: var(--opaline-accent-primary);"));
+    assert!(css.ends_with('}'));
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "terminal-schemes")]
+
+use std::fmt::Write as _;
+use std::fs;
+
+use opaline::adapters::terminal_schemes::{
+    from_file, from_iterm_str, from_pywal_str, from_xresources_str, load_from_file,
+};
+use opaline::names::tokens;
+use opaline::{Contract, OpalineColor, OpalineError, Theme, ThemeFile, ThemeVariant, resolver};
+
+/// Dracula's 16 ANSI colors.
+const DRACULA: [&str; 16] = [
+    "#21222c", "#ff5555", "#50fa7b", "#f1fa8c", "#bd93f9", "#ff79c6", "#8be9fd", "#f8f8f2",
+    "#6272a4", "#ff6e6e", "#69ff94", "#ffffa5", "#d6acff", "#ff92df", "#a4ffff", "#ffffff",
+];
+const BACKGROUND: &str = "#282a36";
+const FOREGROUND: &str = "#f8f8f2";
+const CURSOR: &str = "#bd93f9";
+
+fn pywal_json() -> String {
+    let colors: Vec<String> = DRACULA
+        .iter()
+        .enumerate()
+        .map(|(i, hex)| format!(r#""color{i}": "{hex}""#))
+        .collect();
+    format!(
+        r#"{{
+            "wallpaper": "/home/me/wall.png",
+            "alpha": "100",
+            "special": {{ "background": "{BACKGROUND}", "foreground": "{FOREGROUND}", "cursor": "{CURSOR}" }},
+            "colors": {{ {} }}
+        }}"#,
+        colors.join(", ")
+    )
+}
+
+fn xresources() -> String {
+    let mut text = String::from("! Dracula\n#define bg #282a36\n*.background: bg\n");
+    let _ = writeln!(text, "*foreground:\t{FOREGROUND}");
+    let _ = writeln!(text, "URxvt.cursorColor: {CURSOR}");
+    for (i, hex) in DRACULA.iter().enumerate() {
+        let _ = writeln!(text, "*.color{i}: {hex}");
+    }
+    text
+}
+
+fn iterm_color(key: &str, hex: &str) -> String {
+    let color = OpalineColor::from_hex(hex).expect("valid hex");
+    let component = |c: u8| f32::from(c) / 255.0;
+    format!(
+        "<key>{key}</key>
+        <dict>
+            <key>Alpha Component</key><real>1</real>
+            <key>Blue Component</key><real>{}</real>
+            <key>Color Space</key><string>sRGB</string>
+            <key>Green Component</key><real>{}</real>
+            <key>Red Component</key><real>{}</real>
+        </dict>",
+        component(color.b),
+        component(color.g),
+        component(color.r),
+    )
+}
+
+fn iterm_plist() -> String {
+    let mut entries: Vec<String> = DRACULA
+        .iter()
+        .enumerate()
+        .map(|(i, hex)| iterm_color(&format!("Ansi {i} Color"), hex))
+        .collect();
+    entries.push(iterm_color("Background Color", BACKGROUND));
+    entries.push(iterm_color("Foreground Color", FOREGROUND));
+    entries.push(iterm_color("Cursor Color", CURSOR));
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+{}
+</dict>
+</plist>"#,
+        entries.join("\n")
+    )
+}
+
+fn resolve(theme_file: &ThemeFile) -> Theme {
+    let resolved = resolver::resolve(theme_file).expect("imported theme resolves");
+    Theme::from_resolved(theme_file.meta.clone(), resolved)
+}
+
+fn imported() -> [ThemeFile; 3] {
+    [
+        from_pywal_str(&pywal_json()).expect("pywal import"),
+        from_xresources_str(&xresources()).expect("Xresources import"),
+        from_iterm_str(&iterm_plist()).expect("iTerm2 import"),
+    ]
+}
+
+#[test]
+fn palette_holds_ansi_and_special_colors() {
+    for theme_file in imported() {
+        let name = &theme_file.meta.name;
+        for (i, hex) in DRACULA.iter().enumerate() {
+            assert_eq!(theme_file.palette[&format!("color{i}")], *hex, "{name}");
+        }
+        assert_eq!(theme_file.palette["background"], BACKGROUND, "{name}");
+        assert_eq!(theme_file.palette["foreground"], FOREGROUND, "{name}");
+        assert_eq!(theme_file.palette["cursor"], CURSOR, "{name}");
+    }
+}
+
+#[test]
+fn semantic_tokens_reference_palette_by_hue() {
+    for theme_file in imported() {
+        let name = &theme_file.meta.name;
+        let token = |t: &str| theme_file.tokens[t].as_str();
+        assert_eq!(token(tokens::BG_BASE), "background", "{name}");
+        assert_eq!(token(tokens::TEXT_PRIMARY), "foreground", "{name}");
+        assert_eq!(token(tokens::ERROR), "color1", "{name}");
+        assert_eq!(token(tokens::SUCCESS), "color2", "{name}");
+        assert_eq!(token(tokens::ACCENT_SECONDARY), "color6", "{name}");
+        assert_eq!(token(tokens::ACCENT_PRIMARY), "color5", "{name}");
+    }
+}
+
+#[test]
+fn imports_satisfy_the_contract() {
+    for theme_file in imported() {
+        let name = theme_file.meta.name.clone();
+        assert!(
+            Contract::standard().check_file(&theme_file).is_satisfied(),
+            "{name}"
+        );
+        let theme = resolve(&theme_file);
+        assert!(theme.validate_contract().is_satisfied(), "{name}");
+        assert_eq!(theme.meta.variant, ThemeVariant::Dark, "{name}");
+    }
+}
+
+#[test]
+fn hue_mapping_ignores_slot_order() {
+    // Swap red and green: `error` should follow the red color.
+    let json = pywal_json()
+        .replace(r##""color1": "#ff5555""##, r##""color1": "#50fa7b""##)
+        .replace(r##""color2": "#50fa7b""##, r##""color2": "#ff5555""##);
+    let theme_file = from_pywal_str(&json).expect("pywal import");
+    assert_eq!(theme_file.tokens[tokens::ERROR], "color2");
+    assert_eq!(theme_file.tokens[tokens::SUCCESS], "color1");
+}
+
+#[test]
+fn light_schemes_are_detected() {
+    let json = pywal_json().replace(BACKGROUND, "#fafafa").replace(
+        r##""foreground": "#f8f8f2""##,
+        r##""foreground": "#383a42""##,
+    );
+    let theme = resolve(&from_pywal_str(&json).expect("pywal import"));
+    assert_eq!(theme.meta.variant, ThemeVariant::Light);
+}
+
+#[test]
+fn xresources_accepts_x11_color_syntax() {
+    let text = xresources()
+        .replace("*.color1: #ff5555", "*color1: rgb:ff/55/55")
+        .replace("*.color2: #50fa7b", "*.color2: rgb:5050/fafa/7b7b")
+        .replace("*.color3: #f1fa8c", "XTerm*color3: #fff");
+    let theme_file = from_xresources_str(&text).expect("Xresources import");
+    assert_eq!(theme_file.palette["color1"], "#ff5555");
+    assert_eq!(theme_file.palette["color2"], "#50fa7b");
+    assert_eq!(theme_file.palette["color3"], "#ffffff");
+}
+
+#[test]
+fn missing_colors_are_reported() {
+    let text = xresources().replace("*.color7: #f8f8f2\n", "");
+    let err = from_xresources_str(&text).expect_err("color7 missing");
+    assert!(
+        matches!(&err, OpalineError::InvalidFormat { format, message }
+            if format == "Xresources" && message.contains("color7")),
+        "{err}"
+    );
+
+    let err = from_pywal_str("{ \"colors\": {} }").expect_err("no special colors");
+    assert!(matches!(err, OpalineError::InvalidFormat { .. }));
+
+    let err = from_iterm_str("<plist><dict></dict></plist>").expect_err("no colors");
+    assert!(err.to_string().contains("Ansi 0 Color"), "{err}");
+}
+
+#[test]
+fn files_pick_format_by_extension() {
+    let unique = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("opaline-schemes-{unique}"));
+    fs::create_dir_all(&dir).expect("create temp dir");
+
+    for (file, contents) in [
+        ("colors.json", pywal_json()),
+        ("Dracula.itermcolors", iterm_plist()),
+        (".Xresources", xresources()),
+    ] {
+        let path = dir.join(file);
+        fs::write(&path, contents).expect("write scheme");
+        let theme_file = from_file(&path).expect("import file");
+        assert_eq!(theme_file.palette["color5"], "#ff79c6", "{file}");
+        let theme = load_from_file(&path).expect("load file");
+        assert_eq!(theme.meta.name, theme_file.meta.name);
+    }
+    let dracula = from_file(dir.join("Dracula.itermcolors")).expect("import file");
+    assert_eq!(dracula.meta.name, "Dracula");
+
+    fs::remove_dir_all(&dir).ok();
+}
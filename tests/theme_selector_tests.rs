@@ -2,9 +2,13 @@
 
 use std::sync::{Mutex, MutexGuard, OnceLock};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
 
-use opaline::{Theme, ThemeSelectorAction, ThemeSelectorState, current, set_theme};
+use opaline::{
+    Theme, ThemeHistory, ThemeHistoryStore, ThemeSelectorAction, ThemeSelectorState, ThemeVariant,
+    TomlHistoryStore, current, set_theme,
+};
 
 fn global_lock() -> MutexGuard<'static, ()> {
     static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
@@ -55,3 +59,418 @@ fn esc_restores_original_theme_snapshot() {
 
     set_theme((*previous).clone());
 }
+
+#[test]
+fn selector_renders_with_theme_colors() {
+    use opaline::ThemeSelector;
+    use opaline::adapters::buffer::buffer_to_ansi;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::StatefulWidget;
+
+    let _guard = global_lock();
+    let previous = current();
+
+    let theme = opaline::load_by_name("silkcircuit-neon").expect("builtin theme");
+    set_theme(theme);
+    let mut state = ThemeSelectorState::new();
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    ThemeSelector::new().render(buf.area, &mut buf, &mut state);
+    let ansi = buffer_to_ansi(&buf);
+
+    assert_eq!(ansi.lines().count(), 24);
+    assert!(ansi.contains("Select Theme"));
+    // text.primary (#f8f8f2) as a truecolor foreground
+    assert!(ansi.contains("38;2;248;248;242"));
+
+    set_theme((*previous).clone());
+}
+
+fn type_filter(state: &mut ThemeSelectorState, text: &str) {
+    for c in text.chars() {
+        state.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    }
+}
+
+fn filtered_ids(state: &ThemeSelectorState) -> Vec<String> {
+    state
+        .filtered_themes()
+        .map(|info| info.name.clone())
+        .collect()
+}
+
+#[test]
+fn fuzzy_filter_ranks_best_match_first() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "tns");
+    assert_eq!(
+        state.selected_theme().map(|info| info.name.as_str()),
+        Some("tokyo-night-storm")
+    );
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn variant_and_author_prefixes_narrow_the_list() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "light:");
+    assert!(
+        state
+            .filtered_themes()
+            .all(|info| info.variant == ThemeVariant::Light)
+    );
+    assert!(filtered_ids(&state).contains(&"catppuccin-latte".to_string()));
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "dark: author:catppuccin");
+    assert_eq!(
+        filtered_ids(&state),
+        [
+            "catppuccin-frappe",
+            "catppuccin-macchiato",
+            "catppuccin-mocha"
+        ]
+    );
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn filter_matches_description_and_id() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "arctic");
+    assert_eq!(filtered_ids(&state), ["nord"]);
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "github-dark-dimmed");
+    assert_eq!(filtered_ids(&state), ["github-dark-dimmed"]);
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn matched_characters_are_highlighted() {
+    use opaline::ThemeSelector;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::Modifier;
+    use ratatui::widgets::StatefulWidget;
+
+    let _guard = global_lock();
+    let previous = current();
+
+    let mut state = ThemeSelectorState::new();
+    type_filter(&mut state, "drac");
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    ThemeSelector::new().render(buf.area, &mut buf, &mut state);
+
+    let row = (0..24)
+        .find(|&y| {
+            (0..40)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .contains("Dracula")
+        })
+        .expect("Dracula row");
+    let x = (0..40)
+        .find(|&x| buf[(x, row)].symbol() == "D")
+        .expect("name start");
+    for offset in 0..4 {
+        assert!(
+            buf[(x + offset, row)]
+                .modifier
+                .contains(Modifier::UNDERLINED)
+        );
+    }
+    assert!(!buf[(x + 4, row)].modifier.contains(Modifier::UNDERLINED));
+
+    set_theme((*previous).clone());
+}
+
+fn click(column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn render_selector(state: &mut ThemeSelectorState) -> ratatui::buffer::Buffer {
+    use opaline::ThemeSelector;
+    use ratatui::buffer::Buffer;
+    use ratatui::widgets::StatefulWidget;
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    ThemeSelector::new().render(buf.area, &mut buf, state);
+    buf
+}
+
+fn row_of(buf: &ratatui::buffer::Buffer, name: &str) -> u16 {
+    (0..buf.area.height)
+        .find(|&y| {
+            (0..40)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .contains(name)
+        })
+        .expect("theme row")
+}
+
+#[test]
+fn click_previews_and_double_click_selects() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let area = Rect::new(0, 0, 80, 24);
+    let mut state = ThemeSelectorState::new();
+    let buf = render_selector(&mut state);
+    let row = row_of(&buf, "Dracula");
+
+    assert_eq!(
+        state.handle_mouse(click(8, row), area),
+        ThemeSelectorAction::Navigate
+    );
+    assert_eq!(
+        state.selected_theme().map(|info| info.name.as_str()),
+        Some("dracula")
+    );
+    assert_eq!(current().meta.name, "Dracula");
+
+    assert_eq!(
+        state.handle_mouse(click(8, row), area),
+        ThemeSelectorAction::Select("dracula".to_string())
+    );
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn clicks_off_entries_are_ignored() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let area = Rect::new(0, 0, 80, 24);
+    let mut state = ThemeSelectorState::new();
+    let buf = render_selector(&mut state);
+    let header = row_of(&buf, "Dark Themes");
+
+    // Section header, preview pane, and outside the widget.
+    assert_eq!(
+        state.handle_mouse(click(8, header), area),
+        ThemeSelectorAction::Noop
+    );
+    assert_eq!(
+        state.handle_mouse(click(70, header + 2), area),
+        ThemeSelectorAction::Noop
+    );
+    assert_eq!(
+        state.handle_mouse(click(8, 30), area),
+        ThemeSelectorAction::Noop
+    );
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn scroll_wheel_moves_the_cursor() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let area = Rect::new(0, 0, 80, 24);
+    let mut state = ThemeSelectorState::new();
+    render_selector(&mut state);
+    let first = state.selected_theme().map(|info| info.name.clone());
+    let wheel = |kind| MouseEvent {
+        kind,
+        column: 10,
+        row: 10,
+        modifiers: KeyModifiers::NONE,
+    };
+
+    assert_eq!(
+        state.handle_mouse(wheel(MouseEventKind::ScrollUp), area),
+        ThemeSelectorAction::Noop
+    );
+    assert_eq!(
+        state.handle_mouse(wheel(MouseEventKind::ScrollDown), area),
+        ThemeSelectorAction::Navigate
+    );
+    assert_ne!(state.selected_theme().map(|info| info.name.clone()), first);
+    assert_eq!(
+        state.handle_mouse(wheel(MouseEventKind::ScrollUp), area),
+        ThemeSelectorAction::Navigate
+    );
+    assert_eq!(state.selected_theme().map(|info| info.name.clone()), first);
+
+    set_theme((*previous).clone());
+}
+
+/// In-memory store that shares its contents with the test.
+#[derive(Clone, Default)]
+struct MemoryStore(std::rc::Rc<std::cell::RefCell<ThemeHistory>>);
+
+impl ThemeHistoryStore for MemoryStore {
+    fn load(&self) -> Result<ThemeHistory, opaline::OpalineError> {
+        Ok(self.0.borrow().clone())
+    }
+
+    fn save(&self, history: &ThemeHistory) -> Result<(), opaline::OpalineError> {
+        *self.0.borrow_mut() = history.clone();
+        Ok(())
+    }
+}
+
+#[test]
+fn history_records_use_and_caps_recents() {
+    let mut history = ThemeHistory::default();
+    for id in ["a", "b", "c", "d", "e", "f", "b"] {
+        history.record_use(id);
+    }
+    assert_eq!(history.recent, ["b", "f", "e", "d", "c"]);
+
+    assert!(history.toggle_favorite("nord"));
+    assert!(history.is_favorite("nord"));
+    assert!(!history.toggle_favorite("nord"));
+    assert_eq!(history.favorites.len(), 0);
+}
+
+#[test]
+fn favorites_and_recents_are_pinned_first() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let store = MemoryStore::default();
+    *store.0.borrow_mut() = ThemeHistory {
+        favorites: vec!["nord".into(), "missing-theme".into()],
+        recent: vec!["catppuccin-latte".into(), "nord".into()],
+    };
+    let mut state = ThemeSelectorState::new().with_history(store);
+
+    let ids = filtered_ids(&state);
+    assert_eq!(ids[..2], ["nord", "catppuccin-latte"]);
+    assert_eq!(ids.iter().filter(|id| *id == "nord").count(), 2);
+
+    let buf = render_selector(&mut state);
+    let pinned = row_of(&buf, "Pinned");
+    assert_eq!(row_of(&buf, "Nord"), pinned + 1);
+    assert!(row_of(&buf, "Dark Themes") > pinned + 2);
+
+    // Pinned entries follow the filter too.
+    type_filter(&mut state, "light:");
+    assert_eq!(filtered_ids(&state)[0], "catppuccin-latte");
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn selecting_and_favoriting_save_history() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let store = MemoryStore::default();
+    let mut state = ThemeSelectorState::new().with_history(store.clone());
+    type_filter(&mut state, "dracula");
+
+    assert_eq!(
+        state.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+        ThemeSelectorAction::FavoriteToggled
+    );
+    assert_eq!(state.filter(), "dracula");
+    assert_eq!(store.0.borrow().favorites, ["dracula"]);
+    // The cursor stays on the theme, now in its regular group.
+    assert_eq!(
+        state.selected_theme().map(|info| info.name.as_str()),
+        Some("dracula")
+    );
+
+    assert_eq!(
+        state.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+        ThemeSelectorAction::Select("dracula".to_string())
+    );
+    assert_eq!(store.0.borrow().recent, ["dracula"]);
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn favoriting_between_clicks_is_not_a_double_click() {
+    let _guard = global_lock();
+    let previous = current();
+
+    let area = Rect::new(0, 0, 80, 24);
+    let mut state = ThemeSelectorState::new().with_history(MemoryStore::default());
+    let buf = render_selector(&mut state);
+    let position = filtered_ids(&state)
+        .iter()
+        .position(|id| id == "dracula")
+        .expect("dracula listed");
+
+    assert_eq!(
+        state.handle_mouse(click(8, row_of(&buf, "Dracula")), area),
+        ThemeSelectorAction::Navigate
+    );
+    assert_eq!(
+        state.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+        ThemeSelectorAction::FavoriteToggled
+    );
+
+    // Pinning Dracula shifted the list, so another theme now sits at the
+    // clicked position. Clicking it only previews.
+    let buf = render_selector(&mut state);
+    let shifted = state
+        .filtered_themes()
+        .nth(position)
+        .map(|info| info.display_name.clone())
+        .expect("entry at the clicked position");
+    assert_eq!(
+        state.handle_mouse(click(8, row_of(&buf, &shifted)), area),
+        ThemeSelectorAction::Navigate
+    );
+
+    set_theme((*previous).clone());
+}
+
+#[test]
+fn toml_store_round_trips() {
+    let unique = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("opaline-history-{unique}"));
+    let store = TomlHistoryStore::new(dir.join("nested").join("theme-history.toml"));
+
+    assert_eq!(store.load().expect("missing file"), ThemeHistory::default());
+
+    let history = ThemeHistory {
+        favorites: vec!["dracula".into()],
+        recent: vec!["nord".into(), "dracula".into()],
+    };
+    store.save(&history).expect("save");
+    assert_eq!(store.load().expect("load"), history);
+
+    std::fs::write(store.path(), "favorites = 3").expect("write");
+    assert!(matches!(
+        store.load(),
+        Err(opaline::OpalineError::Parse { .. })
+    ));
+
+    // The history file is in the way of the directory this store needs.
+    let blocked = TomlHistoryStore::new(store.path().join("theme-history.toml"));
+    let err = blocked.save(&history).expect_err("parent is a file");
+    assert!(matches!(err, opaline::OpalineError::Write { .. }));
+    assert!(err.to_string().starts_with("I/O error writing "));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
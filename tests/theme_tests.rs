@@ -1,4 +1,8 @@
-use opaline::{OpalineColor, Theme};
+use opaline::names::tokens;
+use opaline::{
+    ColorSource, Dependent, OpalineColor, OpalineStyle, Theme, ThemeBuilder, ThemeTransition,
+    ThemeVariant,
+};
 
 #[test]
 fn register_default_token_does_not_shadow_palette_name() {
@@ -22,3 +26,363 @@ fn register_default_token_inserts_when_missing() {
 
     assert_eq!(theme.try_color("accent.primary"), Some(color));
 }
+
+const PROVENANCE_TOML: &str = r##"
+[meta]
+name = "Provenance"
+
+[palette]
+purple_500 = "#e135ff"
+cyan_400 = "#80ffea"
+
+[tokens]
+"accent.primary" = "purple_500"
+"border.focused" = "accent.primary"
+"text.primary" = "#f8f8f2"
+
+[styles]
+keyword = { fg = "accent.primary", bg = "cyan_400" }
+plain = { fg = "text.primary" }
+
+[gradients]
+primary = ["border.focused", "cyan_400"]
+"##;
+
+#[test]
+fn token_source_walks_the_reference_chain() {
+    let theme = opaline::load_from_str(PROVENANCE_TOML, None).expect("loads");
+
+    assert_eq!(
+        theme.token_source("border.focused"),
+        Some(vec![
+            ColorSource::Token("accent.primary".to_string()),
+            ColorSource::Palette("purple_500".to_string()),
+        ])
+    );
+    assert_eq!(
+        theme.token_source("text.primary"),
+        Some(vec![ColorSource::Hex(OpalineColor::new(248, 248, 242))])
+    );
+    assert_eq!(theme.token_source("missing"), None);
+}
+
+#[test]
+fn style_sources_record_fg_and_bg() {
+    let theme = opaline::load_from_str(PROVENANCE_TOML, None).expect("loads");
+    let sources = theme.style_sources("keyword").expect("style exists");
+
+    assert_eq!(
+        sources.fg,
+        Some(ColorSource::Token("accent.primary".to_string()))
+    );
+    assert_eq!(
+        sources.bg,
+        Some(ColorSource::Palette("cyan_400".to_string()))
+    );
+}
+
+#[test]
+fn dependents_are_transitive() {
+    let theme = opaline::load_from_str(PROVENANCE_TOML, None).expect("loads");
+
+    let mut expected = vec![
+        Dependent::Token("accent.primary".to_string()),
+        Dependent::Token("border.focused".to_string()),
+        Dependent::Style("keyword".to_string()),
+    ];
+    #[cfg(feature = "gradients")]
+    expected.push(Dependent::Gradient("primary".to_string()));
+    assert_eq!(theme.dependents("purple_500"), expected);
+
+    assert_eq!(
+        theme.dependents("text.primary"),
+        vec![Dependent::Style("plain".to_string())]
+    );
+    assert_eq!(theme.dependents("unused").len(), 0);
+}
+
+#[test]
+fn register_token_replaces_provenance() {
+    let mut theme = opaline::load_from_str(PROVENANCE_TOML, None).expect("loads");
+    let color = OpalineColor::new(1, 2, 3);
+
+    theme.register_token("border.focused", color);
+
+    assert_eq!(
+        theme.token_source("border.focused"),
+        Some(vec![ColorSource::Hex(color)])
+    );
+    assert!(
+        !theme
+            .dependents("purple_500")
+            .contains(&Dependent::Token("border.focused".to_string()))
+    );
+}
+
+#[test]
+fn to_theme_file_round_trips_references() {
+    let theme = opaline::load_from_str(PROVENANCE_TOML, None).expect("loads");
+    let file = theme.to_theme_file();
+
+    assert_eq!(file.palette["purple_500"], "#e135ff");
+    assert_eq!(file.tokens["border.focused"], "accent.primary");
+    assert_eq!(file.tokens["text.primary"], "#f8f8f2");
+    assert_eq!(file.styles["keyword"].fg.as_deref(), Some("accent.primary"));
+    assert_eq!(file.styles["keyword"].bg.as_deref(), Some("cyan_400"));
+
+    let resolved = opaline::resolver::resolve(&file).expect("re-resolves");
+    let reloaded = Theme::from_resolved(file.meta, resolved);
+    assert_eq!(
+        reloaded.color("border.focused"),
+        theme.color("border.focused")
+    );
+    assert_eq!(reloaded.style("keyword"), theme.style("keyword"));
+}
+
+// ── from_seeds ───────────────────────────────────────────────────────────
+
+const SEED_BG: OpalineColor = OpalineColor::new(0x1a, 0x1b, 0x26);
+const SEED_FG: OpalineColor = OpalineColor::new(0xc0, 0xca, 0xf5);
+const SEED_ACCENT: OpalineColor = OpalineColor::new(0x7a, 0xa2, 0xf7);
+
+fn seeded(variant: ThemeVariant) -> Theme {
+    ThemeBuilder::from_seeds(SEED_BG, SEED_FG, SEED_ACCENT, variant).build()
+}
+
+#[test]
+fn from_seeds_satisfies_contract() {
+    let theme = seeded(ThemeVariant::Dark);
+    let report = theme.validate_contract();
+    assert!(report.is_satisfied(), "{report}");
+    assert_eq!(theme.meta.name, "Generated");
+    assert!(theme.is_dark());
+}
+
+#[test]
+fn from_seeds_keeps_seeds_and_snapshots_generated_colors() {
+    let theme = seeded(ThemeVariant::Dark);
+
+    assert_eq!(theme.color(tokens::BG_BASE), SEED_BG);
+    assert_eq!(theme.color(tokens::TEXT_PRIMARY), SEED_FG);
+    assert_eq!(theme.color(tokens::ACCENT_PRIMARY), SEED_ACCENT);
+
+    let hex = |token| theme.color(token).to_hex();
+    assert_eq!(hex(tokens::ACCENT_SECONDARY), "#f38168");
+    assert_eq!(hex(tokens::ACCENT_TERTIARY), "#68f1f3");
+    assert_eq!(hex(tokens::SUCCESS), "#68f396");
+    assert_eq!(hex(tokens::ERROR), "#f36874");
+    assert_eq!(hex(tokens::WARNING), "#f3c968");
+    assert_eq!(hex(tokens::INFO), "#68b9f3");
+    assert_eq!(hex(tokens::BG_SELECTION), "#3c3d47");
+    assert_eq!(hex(tokens::TEXT_MUTED), "#757b98");
+}
+
+#[test]
+fn from_seeds_is_deterministic() {
+    let a = seeded(ThemeVariant::Light);
+    let b = seeded(ThemeVariant::Light);
+    for name in a.token_names() {
+        assert_eq!(a.color(name), b.color(name), "{name}");
+    }
+    for name in a.style_names() {
+        assert_eq!(a.style(name), b.style(name), "{name}");
+    }
+}
+
+#[test]
+fn from_seeds_semantic_colors_are_readable() {
+    for (bg, variant) in [
+        (SEED_BG, ThemeVariant::Dark),
+        (OpalineColor::new(0xfa, 0xfa, 0xfa), ThemeVariant::Light),
+    ] {
+        let theme = ThemeBuilder::from_seeds(bg, SEED_FG, SEED_ACCENT, variant).build();
+        for token in [
+            tokens::ACCENT_SECONDARY,
+            tokens::ACCENT_TERTIARY,
+            tokens::SUCCESS,
+            tokens::ERROR,
+            tokens::WARNING,
+            tokens::INFO,
+        ] {
+            let ratio = theme.color(token).contrast_ratio(bg);
+            assert!(ratio >= 4.5, "{variant} {token}: {ratio:.2}");
+        }
+    }
+}
+
+#[test]
+fn from_seeds_entries_can_be_overridden() {
+    let error = OpalineColor::new(255, 0, 0);
+    let theme = ThemeBuilder::from_seeds(SEED_BG, SEED_FG, SEED_ACCENT, ThemeVariant::Dark)
+        .name("Night City")
+        .token(tokens::ERROR, error)
+        .build();
+
+    assert_eq!(theme.meta.name, "Night City");
+    assert_eq!(theme.color(tokens::ERROR), error);
+}
+
+// ── from_colors ──────────────────────────────────────────────────────────
+
+const WALLPAPER: [OpalineColor; 5] = [
+    OpalineColor::new(0x10, 0x14, 0x2c),
+    OpalineColor::new(0x3a, 0x3f, 0x58),
+    OpalineColor::new(0xf2, 0x8c, 0x28),
+    OpalineColor::new(0xe8, 0x95, 0x30),
+    OpalineColor::new(0x1f, 0xb5, 0xa8),
+];
+
+#[test]
+fn from_colors_satisfies_contract_for_both_variants() {
+    for variant in [ThemeVariant::Dark, ThemeVariant::Light] {
+        let theme = ThemeBuilder::from_colors(&WALLPAPER, variant).build();
+        let report = theme.validate_contract();
+        assert!(report.is_satisfied(), "{variant}: {report}");
+        assert_eq!(theme.meta.variant, variant);
+
+        let bg = theme.color(tokens::BG_BASE);
+        let ratio = theme.color(tokens::TEXT_PRIMARY).contrast_ratio(bg);
+        assert!(ratio >= 7.0, "{variant} text: {ratio:.2}");
+    }
+}
+
+#[test]
+fn from_colors_picks_distinct_colorful_accents() {
+    let theme = ThemeBuilder::from_colors(&WALLPAPER, ThemeVariant::Dark).build();
+    let hue = |token| theme.color(token).to_hsl().0;
+
+    // Orange first, teal second; the near-duplicate orange is skipped.
+    assert!((25.0..35.0).contains(&hue(tokens::ACCENT_PRIMARY)));
+    assert!((170.0..180.0).contains(&hue(tokens::ACCENT_SECONDARY)));
+    assert!(theme.is_dark());
+    assert!(theme.color(tokens::BG_BASE).relative_luminance() < 0.05);
+}
+
+#[test]
+fn from_colors_without_input_still_builds() {
+    let theme = ThemeBuilder::from_colors(&[], ThemeVariant::Light).build();
+    assert!(theme.validate_contract().is_satisfied());
+    assert!(!theme.is_dark());
+}
+
+fn crossfade_pair() -> (Theme, Theme) {
+    let a = Theme::builder("From")
+        .palette("base", OpalineColor::new(0, 0, 0))
+        .token(tokens::BG_BASE, OpalineColor::new(0, 0, 0))
+        .token("only.a", OpalineColor::new(1, 1, 1))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(0, 0, 200)).bold(),
+        )
+        .build();
+    let b = Theme::builder("To")
+        .variant(ThemeVariant::Light)
+        .palette("base", OpalineColor::new(200, 100, 0))
+        .token(tokens::BG_BASE, OpalineColor::new(200, 200, 200))
+        .token("only.b", OpalineColor::new(2, 2, 2))
+        .style("keyword", OpalineStyle::fg(OpalineColor::new(200, 0, 0)))
+        .build();
+    (a, b)
+}
+
+#[test]
+fn interpolate_lerps_shared_colors() {
+    let (a, b) = crossfade_pair();
+    let mid = Theme::interpolate(&a, &b, 0.25);
+
+    assert_eq!(mid.color(tokens::BG_BASE), OpalineColor::new(50, 50, 50));
+    assert_eq!(
+        mid.palette_color("base"),
+        Some(OpalineColor::new(50, 25, 0))
+    );
+    let keyword = mid.style("keyword");
+    assert_eq!(keyword.fg, Some(OpalineColor::new(50, 0, 150)));
+    assert!(keyword.bold);
+}
+
+#[test]
+fn interpolate_takes_the_rest_from_the_nearer_theme() {
+    let (a, b) = crossfade_pair();
+
+    let early = Theme::interpolate(&a, &b, 0.4);
+    assert_eq!(early.meta.name, "From");
+    assert!(early.has_token("only.a"));
+    assert!(!early.has_token("only.b"));
+
+    let late = Theme::interpolate(&a, &b, 0.6);
+    assert_eq!(late.meta.name, "To");
+    assert_eq!(late.meta.variant, ThemeVariant::Light);
+    assert!(late.has_token("only.b"));
+    assert!(!late.style("keyword").bold);
+}
+
+#[test]
+fn interpolate_endpoints_are_exact() {
+    let (a, b) = crossfade_pair();
+    assert_eq!(
+        Theme::interpolate(&a, &b, 0.0).color(tokens::BG_BASE),
+        a.color(tokens::BG_BASE)
+    );
+    assert_eq!(
+        Theme::interpolate(&a, &b, 7.0).color(tokens::BG_BASE),
+        b.color(tokens::BG_BASE)
+    );
+}
+
+#[cfg(feature = "gradients")]
+#[test]
+fn interpolate_resamples_gradients() {
+    use opaline::Gradient;
+
+    let black = OpalineColor::new(0, 0, 0);
+    let white = OpalineColor::new(255, 255, 255);
+    let a = Theme::builder("A")
+        .gradient("g", Gradient::new(vec![black, black]))
+        .build();
+    let b = Theme::builder("B")
+        .gradient("g", Gradient::new(vec![white, black, white]))
+        .build();
+
+    let mid = Theme::interpolate(&a, &b, 0.5);
+    let stops = mid
+        .get_gradient("g")
+        .expect("gradient kept")
+        .stops()
+        .to_vec();
+    assert_eq!(
+        stops,
+        vec![black.lerp(white, 0.5), black, black.lerp(white, 0.5)]
+    );
+}
+
+#[test]
+fn transition_steps_to_the_target() {
+    let (a, b) = crossfade_pair();
+    let mut transition = ThemeTransition::new(a, b, 4);
+    assert!(transition.progress().abs() < f32::EPSILON);
+
+    let first = transition.advance();
+    assert_eq!(first.color(tokens::BG_BASE), OpalineColor::new(50, 50, 50));
+    for _ in 0..3 {
+        transition.advance();
+    }
+    assert!(transition.is_finished());
+    assert_eq!(transition.advance().meta.name, "To");
+    assert!((transition.progress() - 1.0).abs() < f32::EPSILON);
+}
+
+#[cfg(feature = "global-state")]
+#[test]
+fn transition_ticks_the_global_theme() {
+    let (a, b) = crossfade_pair();
+    opaline::set_theme(a);
+    let mut transition = opaline::transition_to(b, 2);
+
+    assert!(transition.tick());
+    assert_eq!(
+        opaline::current().color(tokens::BG_BASE),
+        OpalineColor::new(100, 100, 100)
+    );
+    assert!(!transition.tick());
+    assert_eq!(opaline::current().meta.name, "To");
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "tree-sitter")]
+
+use opaline::adapters::tree_sitter::{highlight_style, highlight_styles};
+use opaline::{OpalineColor, OpalineStyle, Theme};
+
+fn test_theme() -> Theme {
+    Theme::builder("Test")
+        .token("code.keyword", OpalineColor::new(225, 53, 255))
+        .token("code.string", OpalineColor::new(241, 250, 140))
+        .token("code.function", OpalineColor::new(128, 255, 234))
+        .token("text.secondary", OpalineColor::new(188, 188, 202))
+        .style(
+            "keyword",
+            OpalineStyle::fg(OpalineColor::new(225, 53, 255)).bold(),
+        )
+        .style(
+            "string.escape",
+            OpalineStyle::fg(OpalineColor::new(255, 106, 193)),
+        )
+        .build()
+}
+
+#[test]
+fn exact_style_match_wins() {
+    let theme = test_theme();
+    let style = highlight_style(&theme, "string.escape");
+    assert_eq!(style.fg, Some(OpalineColor::new(255, 106, 193)));
+}
+
+#[test]
+fn dotted_prefix_falls_back_to_parent_style() {
+    let theme = test_theme();
+    let style = highlight_style(&theme, "keyword.function");
+    assert_eq!(style.fg, Some(OpalineColor::new(225, 53, 255)));
+    assert!(style.bold);
+}
+
+#[test]
+fn falls_back_to_code_token() {
+    let theme = test_theme();
+    let style = highlight_style(&theme, "string.special");
+    assert_eq!(style.fg, Some(OpalineColor::new(241, 250, 140)));
+    assert!(!style.bold);
+}
+
+#[test]
+fn alias_table_covers_common_captures() {
+    let theme = test_theme();
+    assert_eq!(
+        highlight_style(&theme, "function.method").fg,
+        Some(OpalineColor::new(128, 255, 234))
+    );
+    assert_eq!(
+        highlight_style(&theme, "punctuation.bracket").fg,
+        Some(OpalineColor::new(188, 188, 202))
+    );
+}
+
+#[test]
+fn leading_at_is_ignored() {
+    let theme = test_theme();
+    assert_eq!(
+        highlight_style(&theme, "@keyword"),
+        highlight_style(&theme, "keyword")
+    );
+}
+
+#[test]
+fn unknown_name_is_empty_style() {
+    let theme = test_theme();
+    assert_eq!(
+        highlight_style(&theme, "nonsense.thing"),
+        OpalineStyle::new()
+    );
+}
+
+#[test]
+fn styles_are_parallel_to_names() {
+    let theme = test_theme();
+    let names = ["keyword", "nonsense", "string"];
+    let styles = highlight_styles(&theme, &names);
+    assert_eq!(styles.len(), 3);
+    assert!(styles[0].bold);
+    assert_eq!(styles[1], OpalineStyle::new());
+    assert_eq!(styles[2].fg, Some(OpalineColor::new(241, 250, 140)));
+}
+
+#[cfg(feature = "ratatui")]
+mod lines {
+    use opaline::adapters::tree_sitter::{HighlightEvent, highlight_lines, highlight_styles};
+    use opaline::{OpalineColor, Theme};
+    use ratatui_core::style::{Color, Style};
+
+    #[test]
+    fn events_render_to_lines() {
+        let theme = super::test_theme();
+        let styles = highlight_styles(&theme, &["keyword", "string"]);
+        let source = "let s = \"hi\";\nfn x";
+        let events = vec![
+            HighlightEvent::HighlightStart(0),
+            HighlightEvent::Source { start: 0, end: 3 },
+            HighlightEvent::HighlightEnd,
+            HighlightEvent::Source { start: 3, end: 8 },
+            HighlightEvent::HighlightStart(1),
+            HighlightEvent::Source { start: 8, end: 12 },
+            HighlightEvent::HighlightEnd,
+            HighlightEvent::Source { start: 12, end: 14 },
+            HighlightEvent::HighlightStart(0),
+            HighlightEvent::Source { start: 14, end: 16 },
+            HighlightEvent::HighlightEnd,
+            HighlightEvent::Source { start: 16, end: 18 },
+        ];
+
+        let lines = highlight_lines(source, events, &styles);
+        assert_eq!(lines.len(), 2);
+
+        let first = &lines[0];
+        assert_eq!(first.spans[0].content, "let");
+        assert_eq!(first.spans[0].style.fg, Some(Color::Rgb(225, 53, 255)));
+        assert_eq!(first.spans[1].style, Style::default());
+        assert_eq!(first.spans[2].content, "\"hi\"");
+        assert_eq!(
+            first.spans[2].style.fg,
+            Some(Color::from(OpalineColor::new(241, 250, 140)))
+        );
+
+        let second: String = lines[1].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(second, "fn x");
+    }
+
+    #[test]
+    fn empty_source_yields_single_empty_line() {
+        let lines = highlight_lines("", Vec::new(), &[]);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans.len(), 0);
+    }
+
+    #[test]
+    fn out_of_range_events_are_ignored() {
+        let theme = Theme::builder("Empty").build();
+        let styles = highlight_styles(&theme, &["keyword"]);
+        let events = vec![
+            HighlightEvent::HighlightStart(7),
+            HighlightEvent::Source { start: 0, end: 2 },
+            HighlightEvent::HighlightEnd,
+            HighlightEvent::Source { start: 2, end: 99 },
+        ];
+        let lines = highlight_lines("ab", events, &styles);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans[0].content, "ab");
+    }
+}